@@ -0,0 +1,218 @@
+//!
+//! Constant-time modular arithmetic used by the signing path
+//!
+//! `num-bigint-dig` is variable-time, which leaks the secret number k and the private component x
+//! through timing. Signing therefore converts its inputs into fixed-width [`crypto_bigint::Uint`]s
+//! sized by the bit size of p, and performs all arithmetic involving secrets in Montgomery form
+//!
+
+use crate::{precompute::FixedBaseTable, Components};
+use alloc::{vec, vec::Vec};
+use core::slice::ChunksExact;
+use crypto_bigint::{
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+    Integer, NonZero, Uint, Word, U1024, U2048, U3072, U4096, U512, U8192,
+};
+use num_bigint::BigUint;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Compute the signature parts r and s for the secret number k (big-endian), the private
+/// component x and the hash-derived integer z
+///
+/// Fails if p or q is even, if p is larger than 8192 bits or if k isn't invertible modulo q
+pub fn sign(
+    components: &Components,
+    x: &BigUint,
+    k: &[u8],
+    z: &BigUint,
+) -> signature::Result<(BigUint, BigUint)> {
+    match limbs(components.p()) {
+        Some(U1024::LIMBS) => sign_fixed::<{ U1024::LIMBS }>(components, x, k, z),
+        Some(U2048::LIMBS) => sign_fixed::<{ U2048::LIMBS }>(components, x, k, z),
+        Some(U3072::LIMBS) => sign_fixed::<{ U3072::LIMBS }>(components, x, k, z),
+        Some(U4096::LIMBS) => sign_fixed::<{ U4096::LIMBS }>(components, x, k, z),
+        Some(U8192::LIMBS) => sign_fixed::<{ U8192::LIMBS }>(components, x, k, z),
+        _ => Err(signature::Error::new()),
+    }
+}
+
+/// Number of limbs of the integers used for signing with the modulus p
+///
+/// Returns `None` if p is larger than 8192 bits
+pub fn limbs(p: &BigUint) -> Option<usize> {
+    match p.bits() {
+        0..=1024 => Some(U1024::LIMBS),
+        1025..=2048 => Some(U2048::LIMBS),
+        2049..=3072 => Some(U3072::LIMBS),
+        3073..=4096 => Some(U4096::LIMBS),
+        4097..=8192 => Some(U8192::LIMBS),
+        _ => None,
+    }
+}
+
+/// Compute the signature parts r and s using integers of `LIMBS` limbs
+fn sign_fixed<const LIMBS: usize>(
+    components: &Components,
+    x: &BigUint,
+    k: &[u8],
+    z: &BigUint,
+) -> signature::Result<(BigUint, BigUint)> {
+    let q = components.q();
+    let (p_uint, q_uint) = (
+        to_uint::<LIMBS>(&components.p().to_bytes_be())?,
+        to_uint::<LIMBS>(&q.to_bytes_be())?,
+    );
+
+    // Montgomery arithmetic requires odd moduli
+    if !bool::from(p_uint.is_odd() & q_uint.is_odd()) {
+        return Err(signature::Error::new());
+    }
+
+    let p_params = DynResidueParams::new(&p_uint);
+    let q_params = DynResidueParams::new(&q_uint);
+
+    let k_bytes = k;
+    let mut k = to_uint::<LIMBS>(k_bytes)?;
+    let mut x = {
+        let x_bytes = Zeroizing::new(x.to_bytes_be());
+        to_uint::<LIMBS>(&x_bytes)?
+    };
+
+    // r = (g^k mod p) mod q
+    //
+    // The exponentiation runs in constant time with respect to k. r is part of the signature,
+    // so the final reduction modulo q doesn't need to be constant-time
+    let g_windows = components
+        .g_table()
+        .and_then(FixedBaseTable::montgomery_windows::<LIMBS>);
+    let g_k = match g_windows {
+        Some(windows) if windows.len() >= 2 * k_bytes.len() => {
+            fixed_base_pow(windows, k_bytes, p_params)
+        }
+        _ => {
+            let g = DynResidue::new(&to_uint::<LIMBS>(&components.g().to_bytes_be())?, p_params);
+            g.pow(&k)
+        }
+    };
+    let r = from_uint(&g_k.retrieve()) % q;
+
+    // s = (k^-1 * (z + x * r)) mod q
+    let (k_inv, is_invertible) = DynResidue::new(&k, q_params).invert();
+    let z = DynResidue::new(&to_uint::<LIMBS>(&(z % q).to_bytes_be())?, q_params);
+    let r_residue = DynResidue::new(&to_uint::<LIMBS>(&r.to_bytes_be())?, q_params);
+    let x_residue = DynResidue::new(&x, q_params);
+    let mut s = k_inv.mul(&z.add(&x_residue.mul(&r_residue))).retrieve();
+
+    k.zeroize();
+    x.zeroize();
+
+    if !bool::from(is_invertible) {
+        s.zeroize();
+        return Err(signature::Error::new());
+    }
+
+    let result = from_uint(&s);
+    s.zeroize();
+
+    Ok((r, result))
+}
+
+/// Raise the base of a table to the secret exponent (big-endian) modulo p, given the windows of
+/// the table in Montgomery form
+///
+/// Every window of the table is scanned in full and the entry for the digit of the exponent is
+/// selected in constant time, so the running time doesn't depend on the exponent
+fn fixed_base_pow<const LIMBS: usize>(
+    windows: ChunksExact<'_, Word>,
+    exponent: &[u8],
+    p_params: DynResidueParams<LIMBS>,
+) -> DynResidue<LIMBS> {
+    let mut result = DynResidue::one(p_params);
+    let digits = exponent
+        .iter()
+        .rev()
+        .flat_map(|byte| [byte & 0x0f, byte >> 4]);
+
+    let mut entry = Uint::<LIMBS>::ZERO;
+    for (window, digit) in windows.zip(digits) {
+        let mut selected = Uint::<LIMBS>::ZERO;
+        for (j, words) in window.chunks_exact(LIMBS).enumerate() {
+            entry.as_words_mut().copy_from_slice(words);
+            selected.conditional_assign(&entry, digit.ct_eq(&(j as u8)));
+        }
+
+        result = result.mul(&DynResidue::from_montgomery(selected, p_params));
+        selected.zeroize();
+    }
+
+    result
+}
+
+/// Reduce the random integer c (big-endian) into a secret number k = (c mod (q - 1)) + 1, as
+/// defined by FIPS 186-4 Appendix B.2.1
+///
+/// # Returns
+///
+/// Secret number k as a big-endian byte string of the byte length of q
+pub fn reduce_secret_number(q: &BigUint, c: &[u8]) -> signature::Result<Zeroizing<Vec<u8>>> {
+    match c.len().max((q.bits() + 7) / 8) * 8 {
+        0..=512 => reduce_secret_number_fixed::<{ U512::LIMBS }>(q, c),
+        513..=1024 => reduce_secret_number_fixed::<{ U1024::LIMBS }>(q, c),
+        1025..=2048 => reduce_secret_number_fixed::<{ U2048::LIMBS }>(q, c),
+        2049..=4096 => reduce_secret_number_fixed::<{ U4096::LIMBS }>(q, c),
+        4097..=8192 => reduce_secret_number_fixed::<{ U8192::LIMBS }>(q, c),
+        _ => Err(signature::Error::new()),
+    }
+}
+
+/// Reduce the random integer c into a secret number k using integers of `LIMBS` limbs
+fn reduce_secret_number_fixed<const LIMBS: usize>(
+    q: &BigUint,
+    c: &[u8],
+) -> signature::Result<Zeroizing<Vec<u8>>> {
+    let q_bytes = q.to_bytes_be();
+    let q_minus_one = to_uint::<LIMBS>(&q_bytes)?.wrapping_sub(&Uint::ONE);
+    let q_minus_one = Option::from(NonZero::new(q_minus_one)).ok_or_else(signature::Error::new)?;
+
+    // The reduction runs in constant time with respect to c
+    let mut c = to_uint::<LIMBS>(c)?;
+    let mut k = c.rem(&q_minus_one).wrapping_add(&Uint::ONE);
+
+    let mut k_bytes = Zeroizing::new(Vec::with_capacity(Uint::<LIMBS>::BYTES));
+    for word in k.as_words().iter().rev() {
+        k_bytes.extend_from_slice(&word.to_be_bytes());
+    }
+
+    c.zeroize();
+    k.zeroize();
+
+    Ok(Zeroizing::new(
+        k_bytes[Uint::<LIMBS>::BYTES - q_bytes.len()..].to_vec(),
+    ))
+}
+
+/// Convert a big-endian byte string into an integer of `LIMBS` limbs
+///
+/// Fails if the value doesn't fit
+fn to_uint<const LIMBS: usize>(bytes: &[u8]) -> signature::Result<Uint<LIMBS>> {
+    let len = Uint::<LIMBS>::BYTES;
+    if bytes.len() > len {
+        return Err(signature::Error::new());
+    }
+
+    let mut padded = Zeroizing::new(vec![0; len]);
+    padded[len - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(Uint::from_be_slice(&padded))
+}
+
+/// Convert an integer of `LIMBS` limbs into a `BigUint`
+fn from_uint<const LIMBS: usize>(value: &Uint<LIMBS>) -> BigUint {
+    let mut bytes = Zeroizing::new(Vec::with_capacity(Uint::<LIMBS>::BYTES));
+    for word in value.as_words().iter().rev() {
+        bytes.extend_from_slice(&word.to_be_bytes());
+    }
+
+    BigUint::from_bytes_be(&bytes)
+}
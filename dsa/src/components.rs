@@ -2,19 +2,32 @@
 //! Module containing the definition of the common components container
 //!
 
-use crate::{size::KeySize, two};
-use num_bigint::BigUint;
-use num_traits::Zero;
+#[cfg(feature = "std")]
+use crate::Progress;
+use crate::{
+    arithmetic,
+    precompute::{FixedBaseTable, Precomputation},
+    size::{ApprovedHash, KeySize},
+    two,
+};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::{num::NonZeroUsize, ops::ControlFlow};
+use digest::Digest;
+use num_bigint::{prime::probably_prime, BigUint, RandBigInt};
+use num_traits::{One, Zero};
 use pkcs8::der::{
-    self, asn1::UintRef, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag,
-    Writer,
+    self,
+    asn1::{BitStringRef, UintRef},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, Writer,
 };
 use signature::rand_core::CryptoRngCore;
 
 /// The common components of an DSA keypair
 ///
 /// (the prime p, quotient q and generator g)
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug)]
 #[must_use]
 pub struct Components {
     /// Prime p
@@ -25,6 +38,14 @@ pub struct Components {
 
     /// Generator g
     g: BigUint,
+
+    /// Domain parameter seed and counter used to generate p and q, if known
+    ///
+    /// Not compared, as it isn't part of the encoding of the components
+    validation_parms: Option<ValidationParms>,
+
+    /// Precomputed powers of g, if requested
+    g_table: Precomputation,
 }
 
 impl Components {
@@ -34,7 +55,13 @@ impl Components {
             return Err(signature::Error::new());
         }
 
-        Ok(Self { p, q, g })
+        Ok(Self {
+            p,
+            q,
+            g,
+            validation_parms: None,
+            g_table: Precomputation::default(),
+        })
     }
 
     /// Generate a new pair of common components
@@ -43,6 +70,175 @@ impl Components {
         Self::from_components(p, q, g).expect("[Bug] Newly generated components considered invalid")
     }
 
+    /// Generate a new pair of common components like [`Components::generate`], but test the
+    /// candidates for p on `threads` threads
+    ///
+    /// Each thread draws its candidates from its own HMAC-DRBG instance, seeded from `rng`.
+    /// `progress` is called with the current [`Progress`] after every generated q and every
+    /// tested candidate for p, from whichever thread made the progress. Returning
+    /// [`ControlFlow::Break`] from it cancels the generation
+    ///
+    /// Fails if the generation was cancelled
+    #[cfg(feature = "std")]
+    pub fn generate_parallel<F>(
+        rng: &mut impl CryptoRngCore,
+        key_size: KeySize,
+        threads: NonZeroUsize,
+        progress: F,
+    ) -> signature::Result<Self>
+    where
+        F: Fn(Progress) -> ControlFlow<()> + Sync,
+    {
+        let (p, q, g) =
+            crate::generate::parallel_common_components(rng, key_size, threads, progress)
+                .ok_or_else(signature::Error::new)?;
+
+        Self::from_components(p, q, g)
+    }
+
+    /// Generate a new pair of common components verifiably, as defined by FIPS 186-4
+    /// Appendix A.1.1.2 (p and q) and Appendix A.2.3 (g), using the hash function `D`
+    ///
+    /// The domain parameter seed and counter are retained as [`ValidationParms`], which
+    /// together with `index` allow anyone to check that the components were not chosen
+    /// maliciously using [`Components::validate_pq`] and [`Components::validate_g`]
+    ///
+    /// Fails if the output size of `D` is smaller than the bit size of q
+    pub fn generate_verifiable<D>(
+        rng: &mut impl CryptoRngCore,
+        key_size: KeySize,
+        index: u8,
+    ) -> signature::Result<Self>
+    where
+        D: Digest,
+    {
+        let (p, q, g, validation_parms) =
+            crate::generate::verifiable_components::<D>(rng, key_size, index)?;
+
+        Ok(Self::from_components(p, q, g)?.with_validation_parms(validation_parms))
+    }
+
+    /// Generate a pair of common components reproducibly from a domain parameter seed, as
+    /// defined by FIPS 186-4 Appendix A.1.1.2 (p and q) and Appendix A.2.3 (g, with index 1)
+    ///
+    /// The hash function is selected by the key size: SHA-1 for 1024/160, SHA-224 for 2048/224
+    /// and SHA-256 for 2048/256 and 3072/256. If the seed doesn't lead to valid p and q, it is
+    /// incremented by one until it does, so the same seed always yields the same components.
+    /// The seed actually used is retained as part of the [`ValidationParms`]
+    ///
+    /// Fails if the seed is shorter than the bit size of q
+    pub fn generate_from_seed(seed: &[u8], key_size: KeySize) -> signature::Result<Self> {
+        let (p, q, g, validation_parms) = match ApprovedHash::for_key_size(&key_size) {
+            ApprovedHash::Sha1 => {
+                crate::generate::components_from_seed::<sha1::Sha1>(seed, key_size, 1)
+            }
+            ApprovedHash::Sha224 => {
+                crate::generate::components_from_seed::<sha2::Sha224>(seed, key_size, 1)
+            }
+            ApprovedHash::Sha256 => {
+                crate::generate::components_from_seed::<sha2::Sha256>(seed, key_size, 1)
+            }
+        }?;
+
+        Ok(Self::from_components(p, q, g)?.with_validation_parms(validation_parms))
+    }
+
+    /// Fully validate the components, as required before using domain parameters obtained
+    /// from an untrusted source
+    ///
+    /// Checks that
+    ///
+    /// - the bit sizes of p and q are one of the (L, N) pairs approved by FIPS 186-4 § 4.2
+    /// - p and q are prime, using Miller-Rabin rounds with bases drawn from `rng`
+    /// - q divides p - 1
+    /// - 1 < g < p and g^q mod p = 1
+    pub fn validate(&self, rng: &mut impl CryptoRngCore) -> signature::Result<()> {
+        let (p, q, g) = (&self.p, &self.q, &self.g);
+
+        let (l, n) = (p.bits(), q.bits());
+        let (Ok(l), Ok(n)) = (u32::try_from(l), u32::try_from(n)) else {
+            return Err(signature::Error::new());
+        };
+
+        if !KeySize::is_approved(l, n) {
+            return Err(signature::Error::new());
+        }
+
+        if !is_prime(rng, q) || !is_prime(rng, p) {
+            return Err(signature::Error::new());
+        }
+
+        if !((p - BigUint::one()) % q).is_zero() {
+            return Err(signature::Error::new());
+        }
+
+        if *g < two() || g >= p || g.modpow(q, p) != BigUint::one() {
+            return Err(signature::Error::new());
+        }
+
+        Ok(())
+    }
+
+    /// Attach the domain parameter seed and counter used to generate p and q
+    pub fn with_validation_parms(mut self, validation_parms: ValidationParms) -> Self {
+        self.validation_parms = Some(validation_parms);
+        self
+    }
+
+    /// Precompute a table of powers of g, speeding up the exponentiations with g performed by
+    /// signing and verification at the cost of 32 values of the size of p for every 4 bits of q
+    ///
+    /// The table is shared between clones and doesn't take part in comparisons
+    pub fn with_precomputation(mut self) -> Self {
+        let (p, q, g) = (&self.p, &self.q, &self.g);
+        self.g_table.get_or_insert_with(|| {
+            let table = FixedBaseTable::new(g, p, (q.bits() + 7) / 8 * 8);
+            match arithmetic::limbs(p) {
+                Some(limbs) => table.with_montgomery_form(p, limbs),
+                None => table,
+            }
+        });
+
+        self
+    }
+
+    /// Precomputed powers of g, if any
+    pub(crate) const fn g_table(&self) -> Option<&FixedBaseTable> {
+        self.g_table.get()
+    }
+
+    /// Validate p and q against their domain parameter seed and counter, as defined by
+    /// FIPS 186-4 Appendix A.1.1.3, using the hash function `D`
+    ///
+    /// Fails if no [`ValidationParms`] are present
+    pub fn validate_pq<D>(&self) -> signature::Result<()>
+    where
+        D: Digest,
+    {
+        let validation_parms = self
+            .validation_parms
+            .as_ref()
+            .ok_or_else(signature::Error::new)?;
+
+        crate::generate::validate_pq::<D>(&self.p, &self.q, validation_parms)
+    }
+
+    /// Validate g against the domain parameter seed and `index` used to generate it, as
+    /// defined by FIPS 186-4 Appendix A.2.4, using the hash function `D`
+    ///
+    /// Fails if no [`ValidationParms`] are present
+    pub fn validate_g<D>(&self, index: u8) -> signature::Result<()>
+    where
+        D: Digest,
+    {
+        let validation_parms = self
+            .validation_parms
+            .as_ref()
+            .ok_or_else(signature::Error::new)?;
+
+        crate::generate::validate_g::<D>(&self.p, &self.q, &self.g, validation_parms, index)
+    }
+
     /// DSA prime p
     #[must_use]
     pub const fn p(&self) -> &BigUint {
@@ -60,19 +256,109 @@ impl Components {
     pub const fn g(&self) -> &BigUint {
         &self.g
     }
+
+    /// Domain parameter seed and counter used to generate p and q, if known
+    #[must_use]
+    pub const fn validation_parms(&self) -> Option<&ValidationParms> {
+        self.validation_parms.as_ref()
+    }
+
+    /// Decode the components from an OpenSSL `-----BEGIN DSA PARAMETERS-----` PEM file, as
+    /// produced by `openssl dsaparam`
+    #[cfg(feature = "pem")]
+    pub fn from_pem(s: &str) -> der::Result<Self> {
+        let (label, document) = der::Document::from_pem(s)?;
+        if label != PEM_LABEL {
+            return Err(der::pem::Error::Label.into());
+        }
+
+        Self::from_der(document.as_bytes())
+    }
+
+    /// Encode the components as an OpenSSL `-----BEGIN DSA PARAMETERS-----` PEM file
+    #[cfg(feature = "pem")]
+    pub fn to_pem(&self, line_ending: der::pem::LineEnding) -> der::Result<alloc::string::String> {
+        der::Document::encode_msg(self)?.to_pem(PEM_LABEL, line_ending)
+    }
 }
 
+/// PEM label of the OpenSSL DSA parameters format
+#[cfg(feature = "pem")]
+const PEM_LABEL: &str = "DSA PARAMETERS";
+
+/// Numbers of miller-rabin rounds with random bases performed when validating p and q
+///
+/// This exceeds the number of rounds required by FIPS 186-4 Table C.1 for every approved key size
+const VALIDATION_MR_ROUNDS: usize = 64;
+
+/// Check whether `candidate` is prime
+///
+/// Unlike [`probably_prime`], whose Miller-Rabin bases are derived from the candidate itself,
+/// the bases are drawn from `rng`, so an adversary can't craft a composite which passes the test
+fn is_prime(rng: &mut impl CryptoRngCore, candidate: &BigUint) -> bool {
+    // Small primes, even numbers and Baillie-PSW
+    if !probably_prime(candidate, 0) {
+        return false;
+    }
+
+    let candidate_minus_one = candidate - BigUint::one();
+    let s = candidate_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &candidate_minus_one >> s;
+
+    'rounds: for _ in 0..VALIDATION_MR_ROUNDS {
+        let a = rng.gen_biguint_range(&two(), &candidate_minus_one);
+        let mut x = a.modpow(&d, candidate);
+
+        if x.is_one() || x == candidate_minus_one {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = x.modpow(&two(), candidate);
+
+            if x == candidate_minus_one {
+                continue 'rounds;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Components are compared by p, q and g only
+impl PartialEq for Components {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.p, &self.q, &self.g) == (&other.p, &other.q, &other.g)
+    }
+}
+
+impl PartialOrd for Components {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (&self.p, &self.q, &self.g).partial_cmp(&(&other.p, &other.q, &other.g))
+    }
+}
+
+/// Encoding of the components as a sequence of p, q and g (`Dss-Parms` as defined by
+/// [RFC3279 § 2.3.2])
+///
+/// [`ValidationParms`] aren't part of this encoding, and have to be encoded separately
+///
+/// [RFC3279 § 2.3.2]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.2
 impl<'a> DecodeValue<'a> for Components {
-    fn decode_value<R: Reader<'a>>(reader: &mut R, _header: Header) -> der::Result<Self> {
-        let p = reader.decode::<UintRef<'_>>()?;
-        let q = reader.decode::<UintRef<'_>>()?;
-        let g = reader.decode::<UintRef<'_>>()?;
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let p = reader.decode::<UintRef<'_>>()?;
+            let q = reader.decode::<UintRef<'_>>()?;
+            let g = reader.decode::<UintRef<'_>>()?;
 
-        let p = BigUint::from_bytes_be(p.as_bytes());
-        let q = BigUint::from_bytes_be(q.as_bytes());
-        let g = BigUint::from_bytes_be(g.as_bytes());
+            let p = BigUint::from_bytes_be(p.as_bytes());
+            let q = BigUint::from_bytes_be(q.as_bytes());
+            let g = BigUint::from_bytes_be(g.as_bytes());
 
-        Self::from_components(p, q, g).map_err(|_| Tag::Integer.value_error())
+            Self::from_components(p, q, g).map_err(|_| Tag::Integer.value_error())
+        })
     }
 }
 
@@ -92,3 +378,72 @@ impl EncodeValue for Components {
 }
 
 impl<'a> Sequence<'a> for Components {}
+
+/// Domain parameter seed and counter from which the primes p and q were generated
+///
+/// Encoded as `ValidationParms` as defined by [RFC3279 § 2.3.3]:
+///
+/// ```text
+/// ValidationParms ::= SEQUENCE {
+///     seed         BIT STRING,
+///     pgenCounter  INTEGER }
+/// ```
+///
+/// [RFC3279 § 2.3.3]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.3
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[must_use]
+pub struct ValidationParms {
+    /// Domain parameter seed
+    seed: Vec<u8>,
+
+    /// Counter at which p was found
+    pgen_counter: u32,
+}
+
+impl ValidationParms {
+    /// Construct the validation parameters from the domain parameter seed and counter
+    pub fn new(seed: Vec<u8>, pgen_counter: u32) -> Self {
+        Self { seed, pgen_counter }
+    }
+
+    /// Domain parameter seed
+    #[must_use]
+    pub fn seed(&self) -> &[u8] {
+        &self.seed
+    }
+
+    /// Counter at which p was found
+    #[must_use]
+    pub const fn pgen_counter(&self) -> u32 {
+        self.pgen_counter
+    }
+}
+
+impl<'a> DecodeValue<'a> for ValidationParms {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let seed = BitStringRef::decode(reader)?;
+            let pgen_counter = u32::decode(reader)?;
+
+            let seed = seed
+                .as_bytes()
+                .ok_or_else(|| Tag::BitString.value_error())?;
+
+            Ok(Self::new(seed.into(), pgen_counter))
+        })
+    }
+}
+
+impl EncodeValue for ValidationParms {
+    fn value_len(&self) -> der::Result<Length> {
+        BitStringRef::from_bytes(&self.seed)?.encoded_len()? + self.pgen_counter.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        BitStringRef::from_bytes(&self.seed)?.encode(writer)?;
+        self.pgen_counter.encode(writer)?;
+        Ok(())
+    }
+}
+
+impl<'a> Sequence<'a> for ValidationParms {}
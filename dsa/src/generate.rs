@@ -5,11 +5,18 @@ use signature::rand_core::CryptoRngCore;
 
 mod components;
 mod keypair;
+#[cfg(feature = "std")]
+mod parallel;
 mod secret_number;
 
-pub use self::components::{common as common_components, public as public_component};
-pub use self::keypair::keypair;
-pub use self::secret_number::{secret_number, secret_number_rfc6979};
+pub use self::components::{
+    common as common_components, from_seed as components_from_seed, public as public_component,
+    validate_g, validate_pq, verifiable as verifiable_components,
+};
+pub use self::keypair::{keypair, keypair_from_seed};
+#[cfg(feature = "std")]
+pub use self::parallel::{common as parallel_common_components, Progress};
+pub use self::secret_number::{secret_number, secret_number_hedged, secret_number_rfc6979};
 
 /// Calculate the upper and lower bounds for generating values like p or q
 #[inline]
@@ -5,14 +5,19 @@
 use crate::{
     generate::{calculate_bounds, generate_prime},
     size::KeySize,
-    two, Components,
+    two, Components, ValidationParms,
 };
+use alloc::vec::Vec;
+use digest::Digest;
 use num_bigint::{prime::probably_prime, BigUint, RandBigInt};
-use num_traits::One;
+use num_traits::{One, Pow};
 use signature::rand_core::CryptoRngCore;
 
 /// Numbers of miller-rabin rounds performed to determine primality
-const MR_ROUNDS: usize = 64;
+pub(super) const MR_ROUNDS: usize = 64;
+
+/// Number of candidates for p tested for each q before a new q is generated
+pub(super) const P_CANDIDATES: u64 = 4096;
 
 /// Generate the common components p, q, and g
 ///
@@ -34,35 +39,285 @@ pub fn common(
         }
 
         // Attempt to find a prime p which has a subgroup of the order q
-        for _ in 0..4096 {
-            let m = 'gen_m: loop {
-                let m = rng.gen_biguint(l as usize);
-                if m > p_min && m < p_max {
-                    break 'gen_m m;
-                }
-            };
-            let mr = &m % (two() * &q);
-            let p = m - mr + BigUint::one();
-
+        for _ in 0..P_CANDIDATES {
+            let p = p_candidate(rng, &q, l, &p_min, &p_max);
             if probably_prime(&p, MR_ROUNDS) {
                 break 'gen_pq (p, q);
             }
         }
     };
 
-    // Generate g using the unverifiable method as defined by Appendix A.2.1
-    let e = (&p - BigUint::one()) / &q;
+    let g = unverifiable_generator(&p, &q);
+
+    (p, q, g)
+}
+
+/// Draw a candidate for p, i.e. a random L-bit integer congruent to 1 modulo 2q
+pub(super) fn p_candidate(
+    rng: &mut impl CryptoRngCore,
+    q: &BigUint,
+    l: u32,
+    p_min: &BigUint,
+    p_max: &BigUint,
+) -> BigUint {
+    let m = loop {
+        let m = rng.gen_biguint(l as usize);
+        if m > *p_min && m < *p_max {
+            break m;
+        }
+    };
+    let mr = &m % (two() * q);
+
+    m - mr + BigUint::one()
+}
+
+/// Generate g using the unverifiable method as defined by Appendix A.2.1
+pub(super) fn unverifiable_generator(p: &BigUint, q: &BigUint) -> BigUint {
+    let e = (p - BigUint::one()) / q;
     let mut h = BigUint::one();
-    let g = loop {
-        let g = h.modpow(&e, &p);
+    loop {
+        let g = h.modpow(&e, p);
         if !g.is_one() {
             break g;
         }
 
         h += BigUint::one();
+    }
+}
+
+/// Generate the common components p, q, and g verifiably, using the domain parameter seed
+/// based method as defined by Appendix A.1.1.2 for p and q, and the canonical method as
+/// defined by Appendix A.2.3 for g
+///
+/// # Returns
+///
+/// Tuple of three `BigUint`s and the validation parameters. Ordered like this `(p, q, g, validation_parms)`
+pub fn verifiable<D>(
+    rng: &mut impl CryptoRngCore,
+    KeySize { l, n }: KeySize,
+    index: u8,
+) -> signature::Result<(BigUint, BigUint, BigUint, ValidationParms)>
+where
+    D: Digest,
+{
+    // The output length of the hash function must be at least N
+    if <D as Digest>::output_size() * 8 < n as usize {
+        return Err(signature::Error::new());
+    }
+
+    // The seed length is chosen to be N, the minimum permitted
+    let mut seed = alloc::vec![0; n as usize / 8];
+
+    let (p, q, counter) = loop {
+        rng.fill_bytes(&mut seed);
+
+        let q = q_from_seed::<D>(&seed, n);
+        if !probably_prime(&q, MR_ROUNDS) {
+            continue;
+        }
+
+        if let Some((p, counter)) = p_from_seed::<D>(&seed, &q, l, 4 * l - 1) {
+            break (p, q, counter);
+        }
     };
 
-    (p, q, g)
+    let g = canonical_generator::<D>(&p, &q, &seed, index).ok_or_else(signature::Error::new)?;
+    let validation_parms = ValidationParms::new(seed, counter);
+
+    Ok((p, q, g, validation_parms))
+}
+
+/// Generate the common components p, q, and g reproducibly from a caller-supplied domain
+/// parameter seed, using the same methods as [`verifiable`]
+///
+/// Whenever the seed doesn't yield a prime q, or no prime p is found within `4L - 1`
+/// iterations, the seed is incremented by one (modulo 2^seedlen) and the search restarts, so the
+/// same seed always yields the same components
+///
+/// # Returns
+///
+/// Tuple of three `BigUint`s and the validation parameters. Ordered like this `(p, q, g, validation_parms)`
+pub fn from_seed<D>(
+    seed: &[u8],
+    KeySize { l, n }: KeySize,
+    index: u8,
+) -> signature::Result<(BigUint, BigUint, BigUint, ValidationParms)>
+where
+    D: Digest,
+{
+    // The output length of the hash function and the seed length must both be at least N
+    if <D as Digest>::output_size() * 8 < n as usize || seed.len() * 8 < n as usize {
+        return Err(signature::Error::new());
+    }
+
+    let seed_modulus = two().pow(seed.len() * 8);
+    let mut seed = seed.to_vec();
+
+    let (p, q, counter) = loop {
+        let q = q_from_seed::<D>(&seed, n);
+        if probably_prime(&q, MR_ROUNDS) {
+            if let Some((p, counter)) = p_from_seed::<D>(&seed, &q, l, 4 * l - 1) {
+                break (p, q, counter);
+            }
+        }
+
+        let next = (BigUint::from_bytes_be(&seed) + BigUint::one()) % &seed_modulus;
+        seed = to_bytes(&next, seed.len());
+    };
+
+    let g = canonical_generator::<D>(&p, &q, &seed, index).ok_or_else(signature::Error::new)?;
+    let validation_parms = ValidationParms::new(seed, counter);
+
+    Ok((p, q, g, validation_parms))
+}
+
+/// Validate p and q against their validation parameters as defined by Appendix A.1.1.3
+pub fn validate_pq<D>(
+    p: &BigUint,
+    q: &BigUint,
+    validation_parms: &ValidationParms,
+) -> signature::Result<()>
+where
+    D: Digest,
+{
+    let (l, n) = (p.bits() as u32, q.bits() as u32);
+    let seed = validation_parms.seed();
+    let counter = validation_parms.pgen_counter();
+
+    if !KeySize::is_approved(l, n)
+        || <D as Digest>::output_size() * 8 < n as usize
+        || counter > 4 * l - 1
+        || seed.len() * 8 < n as usize
+    {
+        return Err(signature::Error::new());
+    }
+
+    let computed_q = q_from_seed::<D>(seed, n);
+    if computed_q != *q || !probably_prime(&computed_q, MR_ROUNDS) {
+        return Err(signature::Error::new());
+    }
+
+    match p_from_seed::<D>(seed, q, l, counter) {
+        Some((computed_p, i)) if i == counter && computed_p == *p => Ok(()),
+        _ => Err(signature::Error::new()),
+    }
+}
+
+/// Validate g against the domain parameter seed and index used to generate it as defined by
+/// Appendix A.2.4
+pub fn validate_g<D>(
+    p: &BigUint,
+    q: &BigUint,
+    g: &BigUint,
+    validation_parms: &ValidationParms,
+    index: u8,
+) -> signature::Result<()>
+where
+    D: Digest,
+{
+    if *g < two() || *g >= *p || !g.modpow(q, p).is_one() {
+        return Err(signature::Error::new());
+    }
+
+    match canonical_generator::<D>(p, q, validation_parms.seed(), index) {
+        Some(computed_g) if computed_g == *g => Ok(()),
+        _ => Err(signature::Error::new()),
+    }
+}
+
+/// Derive q from the domain parameter seed as defined by Appendix A.1.1.2 steps 6 and 7
+fn q_from_seed<D: Digest>(seed: &[u8], n: u32) -> BigUint {
+    let two_n1 = two().pow(n - 1);
+    let u = BigUint::from_bytes_be(&D::digest(seed)) % &two_n1;
+    let u_mod_2 = &u % two();
+
+    two_n1 + u + BigUint::one() - u_mod_2
+}
+
+/// Search for p as defined by Appendix A.1.1.2 steps 9 and 10, giving up once `max_counter`
+/// has been exceeded
+///
+/// # Returns
+///
+/// The first prime p found along with the value of `counter` at which it was found
+fn p_from_seed<D: Digest>(
+    seed: &[u8],
+    q: &BigUint,
+    l: u32,
+    max_counter: u32,
+) -> Option<(BigUint, u32)> {
+    let outlen = <D as Digest>::output_size() * 8;
+    let n = (l as usize + outlen - 1) / outlen - 1;
+    let b = l as usize - 1 - n * outlen;
+
+    let seed_value = BigUint::from_bytes_be(seed);
+    let seed_modulus = two().pow(seed.len() * 8);
+    let two_l1 = two().pow(l - 1);
+    let two_b = two().pow(b);
+    let two_q = two() * q;
+
+    let mut offset = 1usize;
+
+    for counter in 0..=max_counter {
+        let mut w = BigUint::default();
+
+        for j in 0..=n {
+            let input = (&seed_value + offset + j) % &seed_modulus;
+            let mut v = BigUint::from_bytes_be(&D::digest(to_bytes(&input, seed.len())));
+
+            if j == n {
+                v %= &two_b;
+            }
+
+            w += v << (j * outlen);
+        }
+
+        let x = w + &two_l1;
+        let c = &x % &two_q;
+        let p = x - c + BigUint::one();
+
+        if p >= two_l1 && probably_prime(&p, MR_ROUNDS) {
+            return Some((p, counter));
+        }
+
+        offset += n + 1;
+    }
+
+    None
+}
+
+/// Derive g canonically from the domain parameter seed and index as defined by Appendix A.2.3
+fn canonical_generator<D: Digest>(
+    p: &BigUint,
+    q: &BigUint,
+    seed: &[u8],
+    index: u8,
+) -> Option<BigUint> {
+    let e = (p - BigUint::one()) / q;
+
+    for count in 1..=u16::MAX {
+        let w = D::new()
+            .chain_update(seed)
+            .chain_update(b"ggen")
+            .chain_update([index])
+            .chain_update(count.to_be_bytes())
+            .finalize();
+
+        let g = BigUint::from_bytes_be(&w).modpow(&e, p);
+        if g >= two() {
+            return Some(g);
+        }
+    }
+
+    None
+}
+
+/// Convert an integer into a big endian byte string of exactly `len` bytes
+fn to_bytes(value: &BigUint, len: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    let mut out = alloc::vec![0; len.saturating_sub(bytes.len())];
+    out.extend_from_slice(&bytes[bytes.len().saturating_sub(len)..]);
+    out
 }
 
 /// Calculate the public component from the common components and the private component
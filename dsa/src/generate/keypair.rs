@@ -15,5 +15,29 @@ pub fn keypair(rng: &mut impl CryptoRngCore, components: Components) -> SigningK
 
     VerifyingKey::from_components(components, y)
         .and_then(|verifying_key| SigningKey::from_components(verifying_key, x))
+        .and_then(|signing_key| {
+            signing_key.pairwise_consistency_check()?;
+            Ok(signing_key)
+        })
         .expect("[Bug] Newly generated keypair considered invalid")
 }
+
+/// Derive a keypair from the seed, which is used as the `returned_bits` of the extra random bits
+/// method as defined by Appendix B.1.1, i.e. x = (c mod (q - 1)) + 1
+///
+/// Fails if the seed is shorter than N + 64 bits
+pub fn keypair_from_seed(components: Components, seed: &[u8]) -> signature::Result<SigningKey> {
+    if seed.len() * 8 < components.q().bits() + 64 {
+        return Err(signature::Error::new());
+    }
+
+    let x_bytes = crate::arithmetic::reduce_secret_number(components.q(), seed)?;
+    let x = BigUint::from_bytes_be(&x_bytes);
+    let y = components::public(&components, &x);
+
+    let signing_key = VerifyingKey::from_components(components, y)
+        .and_then(|verifying_key| SigningKey::from_components(verifying_key, x))?;
+    signing_key.pairwise_consistency_check()?;
+
+    Ok(signing_key)
+}
@@ -0,0 +1,209 @@
+//!
+//! Generate DSA key components using multiple threads
+//!
+
+use crate::{
+    generate::{
+        calculate_bounds,
+        components::{p_candidate, unverifiable_generator, MR_ROUNDS, P_CANDIDATES},
+        generate_prime,
+    },
+    size::KeySize,
+};
+use core::{
+    num::NonZeroUsize,
+    ops::ControlFlow,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use num_bigint::{prime::probably_prime, BigUint};
+use rfc6979::HmacDrbg;
+use sha2::Sha256;
+use signature::rand_core::CryptoRngCore;
+use std::{thread, vec::Vec};
+use zeroize::Zeroizing;
+
+/// Progress of a parallel domain parameter generation, as reported to the progress callback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of primes q generated so far
+    q_candidates: u64,
+
+    /// Number of candidates for p tested so far
+    p_candidates: u64,
+}
+
+impl Progress {
+    /// Number of primes q generated so far
+    ///
+    /// For every q, up to 4096 candidates for p are tested before a new q is generated
+    #[must_use]
+    pub const fn q_candidates(&self) -> u64 {
+        self.q_candidates
+    }
+
+    /// Number of candidates for p tested so far, across all threads
+    #[must_use]
+    pub const fn p_candidates(&self) -> u64 {
+        self.p_candidates
+    }
+}
+
+/// State shared between the threads searching for p
+struct SearchState {
+    /// Number of primes q generated so far
+    q_candidates: u64,
+
+    /// Number of candidates for p claimed for the current q
+    claimed: AtomicU64,
+
+    /// Number of candidates for p tested so far
+    p_candidates: AtomicU64,
+
+    /// Set once p has been found or the generation has been cancelled
+    stop: AtomicBool,
+
+    /// Set once the progress callback requested cancellation
+    cancelled: AtomicBool,
+}
+
+impl SearchState {
+    /// Report progress to the callback, recording a cancellation request
+    fn report<F>(&self, progress: &F)
+    where
+        F: Fn(Progress) -> ControlFlow<()> + Sync,
+    {
+        let current = Progress {
+            q_candidates: self.q_candidates,
+            p_candidates: self.p_candidates.load(Ordering::Relaxed),
+        };
+
+        if progress(current).is_break() {
+            self.cancelled.store(true, Ordering::Relaxed);
+            self.stop.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Generate the common components p, q, and g like [`common`](super::common_components), but
+/// test the candidates for p on `threads` threads
+///
+/// Every thread draws its candidates from its own HMAC-DRBG instance, seeded from `rng`.
+/// `progress` is called after every generated q and every tested candidate for p, and may
+/// cancel the generation by returning [`ControlFlow::Break`]
+///
+/// # Returns
+///
+/// Tuple of three `BigUint`s. Ordered like this `(p, q, g)`, or `None` if cancelled
+pub fn common<F>(
+    rng: &mut impl CryptoRngCore,
+    KeySize { l, n }: KeySize,
+    threads: NonZeroUsize,
+    progress: F,
+) -> Option<(BigUint, BigUint, BigUint)>
+where
+    F: Fn(Progress) -> ControlFlow<()> + Sync,
+{
+    // Calculate the lower and upper bounds of p and q
+    let (p_min, p_max) = calculate_bounds(l);
+    let (q_min, q_max) = calculate_bounds(n);
+
+    let mut state = SearchState {
+        q_candidates: 0,
+        claimed: AtomicU64::new(0),
+        p_candidates: AtomicU64::new(0),
+        stop: AtomicBool::new(false),
+        cancelled: AtomicBool::new(false),
+    };
+
+    let (p, q) = loop {
+        let q = generate_prime(n as usize, rng);
+        if q < q_min || q > q_max {
+            continue;
+        }
+
+        state.q_candidates += 1;
+        state.claimed.store(0, Ordering::Relaxed);
+        state.report(&progress);
+        if state.cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let mut rngs: Vec<_> = (0..threads.get()).map(|_| fork(rng)).collect();
+
+        let (state, q_ref, progress) = (&state, &q, &progress);
+        let (p_min, p_max) = (&p_min, &p_max);
+        let p = thread::scope(|scope| {
+            let workers: Vec<_> = rngs
+                .iter_mut()
+                .map(|thread_rng| {
+                    scope.spawn(move || {
+                        search_p(thread_rng, q_ref, l, p_min, p_max, state, progress)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .filter_map(|worker| worker.join().expect("DSA parameter search thread panicked"))
+                .next()
+        });
+
+        if state.cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        if let Some(p) = p {
+            break (p, q);
+        }
+    };
+
+    let g = unverifiable_generator(&p, &q);
+
+    Some((p, q, g))
+}
+
+/// Test candidates for p until a prime is found, all candidates for the current q have been
+/// claimed, or another thread asked to stop
+fn search_p<F>(
+    rng: &mut HmacDrbg<Sha256>,
+    q: &BigUint,
+    l: u32,
+    p_min: &BigUint,
+    p_max: &BigUint,
+    state: &SearchState,
+    progress: &F,
+) -> Option<BigUint>
+where
+    F: Fn(Progress) -> ControlFlow<()> + Sync,
+{
+    while !state.stop.load(Ordering::Relaxed)
+        && state.claimed.fetch_add(1, Ordering::Relaxed) < P_CANDIDATES
+    {
+        let p = p_candidate(rng, q, l, p_min, p_max);
+        let is_prime = probably_prime(&p, MR_ROUNDS);
+
+        state.p_candidates.fetch_add(1, Ordering::Relaxed);
+        state.report(progress);
+
+        if is_prime {
+            state.stop.store(true, Ordering::Relaxed);
+            return Some(p);
+        }
+    }
+
+    None
+}
+
+/// Fork a new HMAC-DRBG instance for a worker thread off the caller's RNG
+fn fork(rng: &mut impl CryptoRngCore) -> HmacDrbg<Sha256> {
+    let mut entropy_input = Zeroizing::new([0; 32]);
+    let mut nonce = Zeroizing::new([0; 16]);
+    rng.fill_bytes(entropy_input.as_mut());
+    rng.fill_bytes(nonce.as_mut());
+
+    HmacDrbg::new(
+        entropy_input.as_ref(),
+        nonce.as_ref(),
+        b"dsa parallel parameter generation",
+    )
+}
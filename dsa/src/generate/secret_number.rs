@@ -6,11 +6,10 @@ use crate::{Components, SigningKey};
 use alloc::{vec, vec::Vec};
 use core::cmp::min;
 use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset};
-use num_bigint::{BigUint, ModInverse, RandBigInt};
-use num_traits::{One, Zero};
+use num_bigint::BigUint;
 use rfc6979::HmacDrbg;
 use signature::rand_core::CryptoRngCore;
-use zeroize::Zeroize;
+use zeroize::Zeroizing;
 
 /// Reduce the hash into an RFC-6979 appropriate form
 fn reduce_hash(q: &BigUint, hash: &[u8]) -> Vec<u8> {
@@ -30,35 +29,99 @@ fn reduce_hash(q: &BigUint, hash: &[u8]) -> Vec<u8> {
     reduced
 }
 
+/// Encode the private key x as an octet string of the byte length of q, as per RFC 6979 section 2.3.3
+fn int2octets(x: &BigUint, q_byte_len: usize) -> Zeroizing<Vec<u8>> {
+    let x_bytes = Zeroizing::new(x.to_bytes_be());
+    let mut octets = Zeroizing::new(vec![0; q_byte_len]);
+    octets[q_byte_len - x_bytes.len()..].copy_from_slice(&x_bytes);
+
+    octets
+}
+
+/// Check whether 0 < k < q in constant time
+///
+/// Both `k` and `q` are big-endian, and `k` must not be longer than `q`
+fn is_in_range(k: &[u8], q: &[u8]) -> bool {
+    debug_assert!(k.len() <= q.len());
+
+    let k_padding = q.len() - k.len();
+    let mut borrow = 0_u16;
+    let mut nonzero = 0_u8;
+
+    for (i, &q_byte) in q.iter().enumerate().rev() {
+        let k_byte = if i < k_padding { 0 } else { k[i - k_padding] };
+        let diff = u16::from(k_byte)
+            .wrapping_sub(u16::from(q_byte))
+            .wrapping_sub(borrow);
+
+        borrow = (diff >> 8) & 1;
+        nonzero |= k_byte;
+    }
+
+    // k - q borrows if and only if k < q
+    (borrow == 1) & (nonzero != 0)
+}
+
 /// Generate a per-message secret number k deterministically using the method described in RFC 6979
 ///
 /// # Returns
 ///
-/// Secret number k and its modular multiplicative inverse with q
+/// Secret number k as a big-endian byte string
 #[inline]
-pub fn secret_number_rfc6979<D>(signing_key: &SigningKey, hash: &[u8]) -> (BigUint, BigUint)
+pub fn secret_number_rfc6979<D>(signing_key: &SigningKey, hash: &[u8]) -> Zeroizing<Vec<u8>>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    let q = signing_key.verifying_key().components().q();
+    let hash = reduce_hash(q, hash);
+
+    let x_bytes = int2octets(signing_key.x(), q.bits() / 8);
+    let hmac = HmacDrbg::<D>::new(&x_bytes, &hash, &[]);
+
+    secret_number_from_drbg(q, hmac)
+}
+
+/// Generate a per-message secret number k using the hedged construction described in
+/// draft-irtf-cfrg-det-sigs-with-noise, which mixes fresh randomness into RFC 6979
+///
+/// # Returns
+///
+/// Secret number k as a big-endian byte string
+#[inline]
+pub fn secret_number_hedged<D>(
+    rng: &mut impl CryptoRngCore,
+    signing_key: &SigningKey,
+    hash: &[u8],
+) -> Zeroizing<Vec<u8>>
 where
     D: Digest + BlockSizeUser + FixedOutputReset,
 {
     let q = signing_key.verifying_key().components().q();
-    let k_size = q.bits() / 8;
     let hash = reduce_hash(q, hash);
 
-    let mut x_bytes = signing_key.x().to_bytes_be();
-    let mut hmac = HmacDrbg::<D>::new(&x_bytes, &hash, &[]);
-    x_bytes.zeroize();
+    let mut z = vec![0; q.bits() / 8];
+    rng.fill_bytes(&mut z);
 
-    let mut buffer = vec![0; k_size];
+    let x_bytes = int2octets(signing_key.x(), q.bits() / 8);
+    let hmac = HmacDrbg::<D>::new_hedged(&x_bytes, &hash, &z);
+
+    secret_number_from_drbg(q, hmac)
+}
+
+/// Draw a secret number k from an instantiated `HMAC_DRBG`
+fn secret_number_from_drbg<D>(q: &BigUint, mut hmac: HmacDrbg<D>) -> Zeroizing<Vec<u8>>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    let q_bytes = q.to_bytes_be();
+    let k_size = q.bits() / 8;
+    let mut buffer = Zeroizing::new(vec![0; k_size]);
     loop {
         hmac.fill_bytes(&mut buffer);
 
-        let k = BigUint::from_bytes_be(&buffer);
-        if let Some(inv_k) = (&k).mod_inverse(q) {
-            let inv_k = inv_k.to_biguint().unwrap();
-
-            if k > BigUint::zero() && &k < q {
-                return (k, inv_k);
-            }
+        // q is prime, so every k in the range `[1, q-1]` is invertible
+        if is_in_range(&buffer, &q_bytes) {
+            return buffer;
         }
     }
 }
@@ -67,30 +130,29 @@ where
 ///
 /// # Returns
 ///
-/// Secret number k and its modular multiplicative inverse with q
+/// Secret number k as a big-endian byte string
 #[inline]
 pub fn secret_number(
     rng: &mut impl CryptoRngCore,
     components: &Components,
-) -> Option<(BigUint, BigUint)> {
+) -> Option<Zeroizing<Vec<u8>>> {
     let q = components.q();
-    let n = q.bits();
-
-    // Attempt to try a fitting secret number
-    // Give up after 4096 tries
-    for _ in 0..4096 {
-        let c = rng.gen_biguint(n + 64);
-        let k = (c % (q - BigUint::one())) + BigUint::one();
-
-        if let Some(inv_k) = (&k).mod_inverse(q) {
-            let inv_k = inv_k.to_biguint().unwrap();
-
-            // `k` and `k^-1` both have to be in the range `[1, q-1]`
-            if (inv_k > BigUint::zero() && &inv_k < q) && (k > BigUint::zero() && &k < q) {
-                return Some((k, inv_k));
-            }
-        }
+    let c_bits = q.bits() + 64;
+
+    // Draw c as little-endian 32-bit digits, keeping compatibility with the secret numbers
+    // previously generated through `RandBigInt::gen_biguint`
+    let (digits, rem) = (c_bits / 32, c_bits % 32);
+    let mut c = Zeroizing::new(vec![0; (digits + usize::from(rem > 0)) * 4]);
+    rng.fill_bytes(&mut c);
+
+    if rem > 0 {
+        let top = &mut c[digits * 4..];
+        let digit = u32::from_le_bytes([top[0], top[1], top[2], top[3]]) >> (32 - rem);
+        top.copy_from_slice(&digit.to_le_bytes());
     }
 
-    None
+    c.reverse();
+
+    // k = (c mod (q - 1)) + 1
+    crate::arithmetic::reduce_secret_number(q, &c).ok()
 }
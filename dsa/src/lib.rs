@@ -45,19 +45,29 @@
 //!
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use crate::{
-    components::Components, signing_key::SigningKey, size::KeySize, verifying_key::VerifyingKey,
+    components::{Components, ValidationParms},
+    signing_key::SigningKey,
+    size::KeySize,
+    verifying_key::{BatchVerification, VerifyingKey},
 };
 
+#[cfg(feature = "std")]
+pub use crate::generate::Progress;
+
 pub use num_bigint::BigUint;
 pub use pkcs8;
 pub use signature;
 
 use pkcs8::spki::ObjectIdentifier;
 
+mod arithmetic;
 mod components;
 mod generate;
+mod precompute;
 mod signing_key;
 mod size;
 mod verifying_key;
@@ -67,11 +77,44 @@ mod verifying_key;
 /// [RFC3279 2.3.2]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.2
 pub const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10040.4.1");
 
-use alloc::{boxed::Box, vec::Vec};
+/// DSA with SHA-1 signature algorithm object identifier (`dsa-with-sha1`) as defined by
+/// [RFC3279 § 2.2.2].
+///
+/// [RFC3279 § 2.2.2]: https://www.rfc-editor.org/rfc/rfc3279#section-2.2.2
+pub const DSA_SHA1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10040.4.3");
+
+/// DSA with SHA-224 signature algorithm object identifier (`id-dsa-with-sha224`) as defined
+/// by [RFC5758 § 3.1].
+///
+/// [RFC5758 § 3.1]: https://www.rfc-editor.org/rfc/rfc5758#section-3.1
+pub const DSA_SHA224_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.1");
+
+/// DSA with SHA-256 signature algorithm object identifier (`id-dsa-with-sha256`) as defined
+/// by [RFC5758 § 3.1].
+///
+/// [RFC5758 § 3.1]: https://www.rfc-editor.org/rfc/rfc5758#section-3.1
+pub const DSA_SHA256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.2");
+
+/// DSA with SHA-384 signature algorithm object identifier (`id-dsa-with-sha384`) as
+/// registered by NIST in the Computer Security Objects Register.
+pub const DSA_SHA384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.3");
+
+/// DSA with SHA-512 signature algorithm object identifier (`id-dsa-with-sha512`) as
+/// registered by NIST in the Computer Security Objects Register.
+pub const DSA_SHA512_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.4");
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use digest::{const_oid::AssociatedOid, Digest};
 use num_traits::Zero;
-use pkcs8::der::{
-    self, asn1::UintRef, Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader,
-    Sequence, Writer,
+use pkcs8::{
+    der::{
+        self, asn1::BitString, asn1::UintRef, Decode, DecodeValue, Encode, EncodeValue, Header,
+        Length, Reader, Sequence, Writer,
+    },
+    spki::{
+        self, AlgorithmIdentifierOwned, DynAssociatedAlgorithmIdentifier,
+        SignatureBitStringEncoding,
+    },
 };
 use signature::SignatureEncoding;
 
@@ -107,6 +150,38 @@ impl Signature {
     pub fn s(&self) -> &BigUint {
         &self.s
     }
+
+    /// Encode the signature in the fixed-width IEEE P1363 format `r || s`, where both parts are
+    /// big-endian and left-padded with zeros to `q_len` bytes, the byte length of q
+    ///
+    /// Fails if r or s doesn't fit into `q_len` bytes
+    pub fn to_p1363(&self, q_len: usize) -> signature::Result<Vec<u8>> {
+        let mut bytes = vec![0; q_len * 2];
+
+        for (part, out) in [&self.r, &self.s].into_iter().zip(bytes.chunks_mut(q_len)) {
+            let part = part.to_bytes_be();
+            if part.len() > q_len {
+                return Err(signature::Error::new());
+            }
+
+            out[q_len - part.len()..].copy_from_slice(&part);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Decode a signature from the fixed-width IEEE P1363 format `r || s`, where `q_len` is the
+    /// byte length of q
+    ///
+    /// Fails unless `bytes` is exactly `2 * q_len` bytes long
+    pub fn from_p1363(bytes: &[u8], q_len: usize) -> signature::Result<Self> {
+        if q_len == 0 || bytes.len() != q_len * 2 {
+            return Err(signature::Error::new());
+        }
+
+        let (r, s) = bytes.split_at(q_len);
+        Self::from_components(BigUint::from_bytes_be(r), BigUint::from_bytes_be(s))
+    }
 }
 
 impl<'a> DecodeValue<'a> for Signature {
@@ -177,6 +252,197 @@ impl TryFrom<&[u8]> for Signature {
     }
 }
 
+impl SignatureBitStringEncoding for Signature {
+    fn to_bitstring(&self) -> der::Result<BitString> {
+        BitString::new(0, self.to_der()?)
+    }
+}
+
+/// DSA signature in the fixed-width IEEE P1363 encoding `r || s`, as used by e.g. Java's
+/// `SHA256withDSAinP1363Format`, XML-DSig and PKCS#11
+///
+/// Both parts are big-endian and left-padded with zeros to the byte length of q, so the
+/// encoding is exactly twice as long as q. [`VerifyingKey`] rejects signatures whose length
+/// doesn't match its q
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[must_use]
+pub struct P1363Signature {
+    /// Inner signature
+    signature: Signature,
+
+    /// Byte length of each of the signature parts
+    q_len: usize,
+}
+
+impl P1363Signature {
+    /// Create a new fixed-width signature whose parts are `q_len` bytes long
+    ///
+    /// Fails if r or s doesn't fit into `q_len` bytes
+    pub fn new(signature: Signature, q_len: usize) -> signature::Result<Self> {
+        if q_len == 0 || signature.r().bits() > q_len * 8 || signature.s().bits() > q_len * 8 {
+            return Err(signature::Error::new());
+        }
+
+        Ok(Self { signature, q_len })
+    }
+
+    /// DSA signature
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Byte length of each of the signature parts
+    #[must_use]
+    pub fn q_len(&self) -> usize {
+        self.q_len
+    }
+}
+
+impl From<P1363Signature> for Signature {
+    fn from(signature: P1363Signature) -> Signature {
+        signature.signature
+    }
+}
+
+impl From<P1363Signature> for Box<[u8]> {
+    fn from(signature: P1363Signature) -> Box<[u8]> {
+        signature.to_bytes()
+    }
+}
+
+impl SignatureEncoding for P1363Signature {
+    type Repr = Box<[u8]>;
+
+    fn to_bytes(&self) -> Box<[u8]> {
+        SignatureEncoding::to_vec(self).into_boxed_slice()
+    }
+
+    fn to_vec(&self) -> Vec<u8> {
+        self.signature
+            .to_p1363(self.q_len)
+            .expect("[Bug] Signature parts exceed the byte length of q")
+    }
+}
+
+/// The byte length of q is inferred as half the length of `bytes`, which therefore must be even
+impl TryFrom<&[u8]> for P1363Signature {
+    type Error = signature::Error;
+
+    fn try_from(bytes: &[u8]) -> signature::Result<Self> {
+        if bytes.len() % 2 != 0 {
+            return Err(signature::Error::new());
+        }
+
+        let q_len = bytes.len() / 2;
+        Self::new(Signature::from_p1363(bytes, q_len)?, q_len)
+    }
+}
+
+/// An extended [`Signature`] type which is parameterized by an
+/// `ObjectIdentifier` which identifies the DSA variant used by a
+/// particular signature.
+///
+/// Valid `ObjectIdentifiers` are:
+///
+/// - SHA-1: [`DSA_SHA1_OID`] (1.2.840.10040.4.3)
+/// - SHA-224: [`DSA_SHA224_OID`] (2.16.840.1.101.3.4.3.1)
+/// - SHA-256: [`DSA_SHA256_OID`] (2.16.840.1.101.3.4.3.2)
+/// - SHA-384: [`DSA_SHA384_OID`] (2.16.840.1.101.3.4.3.3)
+/// - SHA-512: [`DSA_SHA512_OID`] (2.16.840.1.101.3.4.3.4)
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[must_use]
+pub struct SignatureWithOid {
+    /// Inner signature
+    signature: Signature,
+
+    /// OID which identifies the DSA variant used
+    oid: ObjectIdentifier,
+}
+
+impl SignatureWithOid {
+    /// Create a new signature with an explicitly provided OID
+    ///
+    /// The OID must be one of the DSA signature algorithm OIDs listed above
+    pub fn new(signature: Signature, oid: ObjectIdentifier) -> signature::Result<Self> {
+        if digest_oid_for_dsa(oid).is_none() {
+            return Err(signature::Error::new());
+        }
+
+        Ok(Self { signature, oid })
+    }
+
+    /// Create a new signature, determining the OID from the given digest
+    ///
+    /// Supports SHA-1 and the SHA-224, SHA-256, SHA-384 and SHA-512 digests
+    pub fn new_with_digest<D>(signature: Signature) -> signature::Result<Self>
+    where
+        D: AssociatedOid + Digest,
+    {
+        let oid = dsa_oid_for_digest(D::OID).ok_or_else(signature::Error::new)?;
+        Ok(Self { signature, oid })
+    }
+
+    /// DSA signature
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// DSA signature algorithm OID
+    #[must_use]
+    pub fn oid(&self) -> ObjectIdentifier {
+        self.oid
+    }
+}
+
+impl From<SignatureWithOid> for Signature {
+    fn from(signature: SignatureWithOid) -> Signature {
+        signature.signature
+    }
+}
+
+impl SignatureBitStringEncoding for SignatureWithOid {
+    fn to_bitstring(&self) -> der::Result<BitString> {
+        self.signature.to_bitstring()
+    }
+}
+
+/// The parameters are absent, as required by [RFC3279 § 2.2.2] and [RFC5758 § 3.1]
+///
+/// [RFC3279 § 2.2.2]: https://www.rfc-editor.org/rfc/rfc3279#section-2.2.2
+/// [RFC5758 § 3.1]: https://www.rfc-editor.org/rfc/rfc5758#section-3.1
+impl DynAssociatedAlgorithmIdentifier for SignatureWithOid {
+    fn algorithm_identifier(&self) -> spki::Result<AlgorithmIdentifierOwned> {
+        Ok(AlgorithmIdentifierOwned {
+            oid: self.oid,
+            parameters: None,
+        })
+    }
+}
+
+/// Get the DSA signature algorithm OID for a given digest OID
+const fn dsa_oid_for_digest(digest_oid: ObjectIdentifier) -> Option<ObjectIdentifier> {
+    match digest_oid {
+        sha1::Sha1::OID => Some(DSA_SHA1_OID),
+        sha2::Sha224::OID => Some(DSA_SHA224_OID),
+        sha2::Sha256::OID => Some(DSA_SHA256_OID),
+        sha2::Sha384::OID => Some(DSA_SHA384_OID),
+        sha2::Sha512::OID => Some(DSA_SHA512_OID),
+        _ => None,
+    }
+}
+
+/// Get the digest OID for a given DSA signature algorithm OID
+const fn digest_oid_for_dsa(dsa_oid: ObjectIdentifier) -> Option<ObjectIdentifier> {
+    match dsa_oid {
+        DSA_SHA1_OID => Some(sha1::Sha1::OID),
+        DSA_SHA224_OID => Some(sha2::Sha224::OID),
+        DSA_SHA256_OID => Some(sha2::Sha256::OID),
+        DSA_SHA384_OID => Some(sha2::Sha384::OID),
+        DSA_SHA512_OID => Some(sha2::Sha512::OID),
+        _ => None,
+    }
+}
+
 /// Returns a `BigUint` with the value 2
 #[inline]
 fn two() -> BigUint {
@@ -0,0 +1,219 @@
+//!
+//! Precomputed tables for fixed-base exponentiation and simultaneous multi-exponentiation
+//!
+
+use alloc::{sync::Arc, vec::Vec};
+use core::{cmp::Ordering, fmt, slice::ChunksExact};
+use crypto_bigint::{Limb, Word};
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Window width in bits used by the tables
+pub const WINDOW_BITS: usize = 4;
+
+/// Number of entries in every window of a table
+pub const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// Precomputed powers of a fixed base modulo p
+///
+/// Window i holds base^(j * 2^(4i)) for j in 0..16, so raising the base to an exponent of up to
+/// `4 * windows` bits takes one multiplication per window and no squarings
+///
+/// The powers are shared between clones
+#[derive(Clone)]
+pub struct FixedBaseTable {
+    /// Powers of the base, ordered by window
+    windows: Arc<Vec<[BigUint; WINDOW_SIZE]>>,
+
+    /// Powers of the base in Montgomery form as little-endian words, ordered by window and digit,
+    /// for constant-time signing
+    montgomery: Option<Arc<Vec<Word>>>,
+}
+
+impl FixedBaseTable {
+    /// Precompute the table for raising `base` to exponents of up to `exponent_bits` bits modulo p
+    pub fn new(base: &BigUint, p: &BigUint, exponent_bits: usize) -> Self {
+        let window_count = (exponent_bits + WINDOW_BITS - 1) / WINDOW_BITS;
+        let mut windows = Vec::with_capacity(window_count);
+
+        let mut window_base = base % p;
+        for _ in 0..window_count {
+            let mut window: [BigUint; WINDOW_SIZE] = Default::default();
+            window[0] = BigUint::one();
+            for j in 1..WINDOW_SIZE {
+                window[j] = (&window[j - 1] * &window_base) % p;
+            }
+
+            window_base = (&window[WINDOW_SIZE - 1] * &window_base) % p;
+            windows.push(window);
+        }
+
+        Self {
+            windows: Arc::new(windows),
+            montgomery: None,
+        }
+    }
+
+    /// Also store the powers in Montgomery form modulo p as integers of `limbs` limbs, so signing
+    /// doesn't have to convert them
+    pub fn with_montgomery_form(mut self, p: &BigUint, limbs: usize) -> Self {
+        let shift = limbs * Limb::BITS;
+        let mut words = Vec::with_capacity(self.windows.len() * WINDOW_SIZE * limbs);
+
+        for entry in self.windows.iter().flatten() {
+            let mut bytes = ((entry << shift) % p).to_bytes_le();
+            bytes.resize(limbs * Limb::BYTES, 0);
+            words.extend(bytes.chunks_exact(Limb::BYTES).map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0, |word, &byte| (word << 8) | Word::from(byte))
+            }));
+        }
+
+        self.montgomery = Some(Arc::new(words));
+        self
+    }
+
+    /// Powers of the base in Montgomery form as integers of `LIMBS` limbs, in chunks of
+    /// `WINDOW_SIZE * LIMBS` words per window
+    ///
+    /// Returns `None` unless the table stores them for integers of that width
+    pub fn montgomery_windows<const LIMBS: usize>(&self) -> Option<ChunksExact<'_, Word>> {
+        let words = self.montgomery.as_ref()?;
+        (words.len() == self.windows.len() * WINDOW_SIZE * LIMBS)
+            .then(|| words.chunks_exact(WINDOW_SIZE * LIMBS))
+    }
+
+    /// Raise the base to the exponent modulo p in variable time
+    ///
+    /// Returns `None` if the exponent has more bits than the table covers
+    pub fn pow(&self, exponent: &BigUint, p: &BigUint) -> Option<BigUint> {
+        let digits = window_digits(exponent);
+        if digits.len() > self.windows.len() {
+            return None;
+        }
+
+        let mut result = BigUint::one();
+        for (window, &digit) in self.windows.iter().zip(&digits) {
+            if digit != 0 {
+                result = (result * &window[usize::from(digit)]) % p;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+impl fmt::Debug for FixedBaseTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedBaseTable")
+            .field("windows", &self.windows.len())
+            .finish()
+    }
+}
+
+/// Optional [`FixedBaseTable`], which never takes part in comparisons since it is derived from
+/// the values stored alongside it
+#[derive(Clone, Debug, Default)]
+pub struct Precomputation(Option<FixedBaseTable>);
+
+impl Precomputation {
+    /// Precomputed table, if any
+    pub const fn get(&self) -> Option<&FixedBaseTable> {
+        self.0.as_ref()
+    }
+
+    /// Compute the table using `f` unless it is already present
+    pub fn get_or_insert_with(&mut self, f: impl FnOnce() -> FixedBaseTable) {
+        if self.0.is_none() {
+            self.0 = Some(f());
+        }
+    }
+}
+
+impl PartialEq for Precomputation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for Precomputation {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// Compute g^u1 * y^u2 mod p in variable time
+///
+/// Bases with a table are raised using it, the remaining ones are raised simultaneously using
+/// Straus' method, sharing a single chain of squarings
+pub fn multi_exp(
+    (g, g_table, u1): (&BigUint, Option<&FixedBaseTable>, &BigUint),
+    (y, y_table, u2): (&BigUint, Option<&FixedBaseTable>, &BigUint),
+    p: &BigUint,
+) -> BigUint {
+    let mut result = BigUint::one();
+    let mut remaining = Vec::with_capacity(2);
+
+    for (base, table, exponent) in [(g, g_table, u1), (y, y_table, u2)] {
+        match table.and_then(|table| table.pow(exponent, p)) {
+            Some(power) => result = (result * power) % p,
+            None => remaining.push((base, exponent)),
+        }
+    }
+
+    if remaining.is_empty() {
+        return result;
+    }
+
+    // Small per-base tables of base^j for j in 0..16
+    let small_tables: Vec<Vec<BigUint>> = remaining
+        .iter()
+        .map(|(base, _)| {
+            let mut powers = Vec::with_capacity(WINDOW_SIZE);
+            powers.push(BigUint::one());
+            for j in 1..WINDOW_SIZE {
+                powers.push((&powers[j - 1] * *base) % p);
+            }
+            powers
+        })
+        .collect();
+    let digits: Vec<Vec<u8>> = remaining
+        .iter()
+        .map(|(_, exponent)| window_digits(exponent))
+        .collect();
+    let window_count = digits.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut acc = BigUint::one();
+    for i in (0..window_count).rev() {
+        for _ in 0..WINDOW_BITS {
+            acc = (&acc * &acc) % p;
+        }
+
+        for (powers, digits) in small_tables.iter().zip(&digits) {
+            match digits.get(i) {
+                Some(&digit) if digit != 0 => acc = (acc * &powers[usize::from(digit)]) % p,
+                _ => {}
+            }
+        }
+    }
+
+    (result * acc) % p
+}
+
+/// Split an integer into its 4-bit windows, least significant first
+pub fn window_digits(value: &BigUint) -> Vec<u8> {
+    let bytes = value.to_bytes_le();
+    let mut digits = Vec::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        digits.push(byte & 0x0f);
+        digits.push(byte >> 4);
+    }
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    digits
+}
@@ -1,3 +1,5 @@
+use crate::Components;
+
 /// DSA key size
 pub struct KeySize {
     /// Bit size of p
@@ -22,4 +24,45 @@ impl KeySize {
 
     /// DSA parameter size constant: L = 3072, N = 256
     pub const DSA_3072_256: Self = Self { l: 3072, n: 256 };
+
+    /// Is the given combination of bit sizes of p and q (L, N) approved by FIPS 186-4 § 4.2?
+    pub(crate) fn is_approved(l: u32, n: u32) -> bool {
+        matches!(
+            (l, n),
+            (1024, 160) | (2048, 224) | (2048, 256) | (3072, 256)
+        )
+    }
+}
+
+/// Hash function used by the plain [`Signer`](::signature::Signer) and
+/// [`Verifier`](::signature::Verifier) implementations, selected by the key size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ApprovedHash {
+    /// SHA-1, approved for verifying signatures of 1024/160 keys only
+    Sha1,
+
+    /// SHA-224, used with 2048/224 keys
+    Sha224,
+
+    /// SHA-256, used with 2048/256 and 3072/256 keys as well as all unapproved key sizes
+    Sha256,
+}
+
+impl ApprovedHash {
+    /// Select the hash function for the key size of the given components
+    pub(crate) fn for_components(components: &Components) -> Self {
+        Self::for_key_size(&KeySize {
+            l: components.p().bits() as u32,
+            n: components.q().bits() as u32,
+        })
+    }
+
+    /// Select the hash function for the given key size
+    pub(crate) fn for_key_size(key_size: &KeySize) -> Self {
+        match (key_size.l, key_size.n) {
+            (1024, 160) => Self::Sha1,
+            (2048, 224) => Self::Sha224,
+            _ => Self::Sha256,
+        }
+    }
 }
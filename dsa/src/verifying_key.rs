@@ -2,19 +2,25 @@
 //! Module containing the definition of the public key container
 //!
 
-use crate::{two, Components, Signature, OID};
+use crate::{
+    precompute::{multi_exp, FixedBaseTable, Precomputation},
+    size::ApprovedHash,
+    two, Components, P1363Signature, Signature, SignatureWithOid, DSA_SHA1_OID, DSA_SHA224_OID,
+    DSA_SHA256_OID, DSA_SHA384_OID, DSA_SHA512_OID, OID,
+};
+use alloc::{vec, vec::Vec};
 use core::cmp::min;
 use digest::Digest;
 use num_bigint::{BigUint, ModInverse};
-use num_traits::One;
+use num_traits::{One, Zero};
 use pkcs8::{
     der::{
         asn1::{BitStringRef, UintRef},
         AnyRef, Decode, Encode,
     },
-    spki, AlgorithmIdentifierRef, EncodePublicKey, SubjectPublicKeyInfoRef,
+    spki, AlgorithmIdentifierRef, DecodePublicKey, EncodePublicKey, SubjectPublicKeyInfoRef,
 };
-use signature::{hazmat::PrehashVerifier, DigestVerifier, Verifier};
+use signature::{hazmat::PrehashVerifier, rand_core::CryptoRngCore, DigestVerifier, Verifier};
 
 /// DSA public key.
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
@@ -25,6 +31,9 @@ pub struct VerifyingKey {
 
     /// Public component y
     y: BigUint,
+
+    /// Precomputed powers of y, if requested
+    y_table: Precomputation,
 }
 
 impl VerifyingKey {
@@ -34,7 +43,60 @@ impl VerifyingKey {
             return Err(signature::Error::new());
         }
 
-        Ok(Self { components, y })
+        Ok(Self {
+            components,
+            y,
+            y_table: Precomputation::default(),
+        })
+    }
+
+    /// Validate the public component y as defined by SP 800-89 § 5.3.2, i.e. check that
+    /// 1 < y < p and y^q mod p = 1
+    ///
+    /// This doesn't validate the common components, see [`Components::validate`]
+    pub fn validate(&self) -> signature::Result<()> {
+        let (p, q) = (self.components.p(), self.components.q());
+
+        if self.y < two() || self.y >= *p || self.y.modpow(q, p) != BigUint::one() {
+            return Err(signature::Error::new());
+        }
+
+        Ok(())
+    }
+
+    /// Decode a DER-encoded `SubjectPublicKeyInfo`, fully validating both the common
+    /// components (see [`Components::validate`]) and the public component (see
+    /// [`VerifyingKey::validate`])
+    ///
+    /// Use this instead of [`DecodePublicKey::from_public_key_der`] for keys obtained from
+    /// an untrusted source
+    pub fn from_public_key_der_validated(
+        rng: &mut impl CryptoRngCore,
+        bytes: &[u8],
+    ) -> spki::Result<Self> {
+        let verifying_key = Self::from_public_key_der(bytes)?;
+
+        verifying_key
+            .components
+            .validate(rng)
+            .and_then(|()| verifying_key.validate())
+            .map_err(|_| spki::Error::KeyMalformed)?;
+
+        Ok(verifying_key)
+    }
+
+    /// Precompute tables of powers of both g and y, speeding up verification at the cost of 32
+    /// values of the size of p for every 4 bits of q
+    ///
+    /// Worthwhile when many signatures are verified against the same key. The tables are shared
+    /// between clones and don't take part in comparisons
+    pub fn with_precomputation(mut self) -> Self {
+        self.components = self.components.with_precomputation();
+        let (p, q, y) = (self.components.p(), self.components.q(), &self.y);
+        self.y_table
+            .get_or_insert_with(|| FixedBaseTable::new(y, p, (q.bits() + 7) / 8 * 8));
+
+        self
     }
 
     /// DSA common components
@@ -48,19 +110,89 @@ impl VerifyingKey {
         &self.y
     }
 
+    /// Byte length of q, i.e. of each of the parts of a [`P1363Signature`]
+    pub(crate) fn q_len(&self) -> usize {
+        (self.components.q().bits() + 7) / 8
+    }
+
+    /// Verify a batch of prehashed messages, each against its own key
+    ///
+    /// Items are grouped by their common components, and the inverses of s within each group
+    /// are computed using a single modular inversion (Montgomery's batch inversion trick)
+    ///
+    /// Like [`PrehashVerifier::verify_prehash`], the prehashes are truncated to the byte length
+    /// of q, so any digest may be used
+    pub fn verify_batch(items: &[(&VerifyingKey, &[u8], &Signature)]) -> BatchVerification {
+        let mut failed = Vec::new();
+        let mut groups: Vec<(&Components, Vec<usize>)> = Vec::new();
+
+        for (index, (verifying_key, _, signature)) in items.iter().enumerate() {
+            let components = verifying_key.components();
+            let q = components.q();
+            if signature.r() >= q || signature.s() >= q || signature.s().is_zero() {
+                failed.push(index);
+                continue;
+            }
+
+            let group = groups
+                .iter_mut()
+                .find(|(group_components, _)| *group_components == components);
+
+            match group {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((components, vec![index])),
+            }
+        }
+
+        for (components, indices) in groups {
+            let q = components.q();
+            let s_values: Vec<&BigUint> = indices.iter().map(|&i| items[i].2.s()).collect();
+
+            match batch_invert(&s_values, q) {
+                Some(inverses) => {
+                    for (&index, w) in indices.iter().zip(&inverses) {
+                        let (verifying_key, hash, signature) = items[index];
+                        if !verifying_key.verify_with_inverse(hash, signature, w) {
+                            failed.push(index);
+                        }
+                    }
+                }
+                // Some s isn't invertible (q isn't prime), fall back to inverting individually
+                None => {
+                    for index in indices {
+                        let (verifying_key, hash, signature) = items[index];
+                        if verifying_key.verify_prehashed(hash, signature) != Some(true) {
+                            failed.push(index);
+                        }
+                    }
+                }
+            }
+        }
+
+        failed.sort_unstable();
+        BatchVerification { failed }
+    }
+
     /// Verify some prehashed data
     #[must_use]
     fn verify_prehashed(&self, hash: &[u8], signature: &Signature) -> Option<bool> {
-        let components = self.components();
-        let (p, q, g) = (components.p(), components.q(), components.g());
-        let (r, s) = (signature.r(), signature.s());
-        let y = self.y();
+        let q = self.components().q();
 
         if signature.r() >= q || signature.s() >= q {
             return Some(false);
         }
 
-        let w = s.mod_inverse(q)?.to_biguint().unwrap();
+        let w = signature.s().mod_inverse(q)?.to_biguint().unwrap();
+
+        Some(self.verify_with_inverse(hash, signature, &w))
+    }
+
+    /// Verify some prehashed data, given the inverse w of s modulo q
+    fn verify_with_inverse(&self, hash: &[u8], signature: &Signature, w: &BigUint) -> bool {
+        let components = self.components();
+        let (p, q, g) = (components.p(), components.q(), components.g());
+        let r = signature.r();
+        let y = self.y();
 
         let n = q.bits() / 8;
         let block_size = hash.len(); // Hash function output size
@@ -68,17 +200,130 @@ impl VerifyingKey {
         let z_len = min(n, block_size);
         let z = BigUint::from_bytes_be(&hash[..z_len]);
 
-        let u1 = (&z * &w) % q;
-        let u2 = (r * &w) % q;
-        let v = (g.modpow(&u1, p) * y.modpow(&u2, p) % p) % q;
+        let u1 = (&z * w) % q;
+        let u2 = (r * w) % q;
+        let v = multi_exp(
+            (g, components.g_table(), &u1),
+            (y, self.y_table.get(), &u2),
+            p,
+        ) % q;
+
+        v == *r
+    }
+}
+
+/// Outcome of [`VerifyingKey::verify_batch`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct BatchVerification {
+    /// Indices of the items that failed to verify, in ascending order
+    failed: Vec<usize>,
+}
+
+impl BatchVerification {
+    /// Did every item verify?
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Indices of the items that failed to verify, in ascending order
+    #[must_use]
+    pub fn failed(&self) -> &[usize] {
+        &self.failed
+    }
+
+    /// Aggregate result, failing if any item failed to verify
+    pub fn result(&self) -> signature::Result<()> {
+        if self.is_valid() {
+            Ok(())
+        } else {
+            Err(signature::Error::new())
+        }
+    }
+}
+
+/// Invert all values modulo q using a single modular inversion
+///
+/// Returns `None` if any of the values isn't invertible
+fn batch_invert(values: &[&BigUint], q: &BigUint) -> Option<Vec<BigUint>> {
+    // prefix[i] is the product of the first i values
+    let mut prefix = Vec::with_capacity(values.len() + 1);
+    prefix.push(BigUint::one());
+    for value in values {
+        let product = (prefix.last()? * *value) % q;
+        prefix.push(product);
+    }
 
-        Some(v == *r)
+    let mut inverse = prefix.last()?.mod_inverse(q)?.to_biguint()?;
+    let mut inverses = vec![BigUint::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        inverses[i] = (&inverse * &prefix[i]) % q;
+        inverse = (inverse * values[i]) % q;
     }
+
+    Some(inverses)
 }
 
+/// The message is hashed with the digest approved for the key size: SHA-1 for 1024/160 keys,
+/// SHA-224 for 2048/224 keys and SHA-256 for 2048/256 and 3072/256 keys
 impl Verifier<Signature> for VerifyingKey {
     fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), signature::Error> {
-        self.verify_digest(sha2::Sha256::new_with_prefix(msg), signature)
+        match ApprovedHash::for_components(&self.components) {
+            ApprovedHash::Sha1 => self.verify_digest(sha1::Sha1::new_with_prefix(msg), signature),
+            ApprovedHash::Sha224 => {
+                self.verify_digest(sha2::Sha224::new_with_prefix(msg), signature)
+            }
+            ApprovedHash::Sha256 => {
+                self.verify_digest(sha2::Sha256::new_with_prefix(msg), signature)
+            }
+        }
+    }
+}
+
+impl Verifier<SignatureWithOid> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &SignatureWithOid) -> Result<(), signature::Error> {
+        let signature_inner = signature.signature();
+
+        match signature.oid() {
+            DSA_SHA1_OID => self.verify_digest(sha1::Sha1::new_with_prefix(msg), signature_inner),
+            DSA_SHA224_OID => {
+                self.verify_digest(sha2::Sha224::new_with_prefix(msg), signature_inner)
+            }
+            DSA_SHA256_OID => {
+                self.verify_digest(sha2::Sha256::new_with_prefix(msg), signature_inner)
+            }
+            DSA_SHA384_OID => {
+                self.verify_digest(sha2::Sha384::new_with_prefix(msg), signature_inner)
+            }
+            DSA_SHA512_OID => {
+                self.verify_digest(sha2::Sha512::new_with_prefix(msg), signature_inner)
+            }
+            _ => Err(signature::Error::new()),
+        }
+    }
+}
+
+impl Verifier<P1363Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &P1363Signature) -> Result<(), signature::Error> {
+        if signature.q_len() != self.q_len() {
+            return Err(signature::Error::new());
+        }
+
+        self.verify(msg, signature.signature())
+    }
+}
+
+impl<D> DigestVerifier<D, P1363Signature> for VerifyingKey
+where
+    D: Digest,
+{
+    fn verify_digest(&self, digest: D, signature: &P1363Signature) -> Result<(), signature::Error> {
+        if signature.q_len() != self.q_len() {
+            return Err(signature::Error::new());
+        }
+
+        self.verify_digest(digest, signature.signature())
     }
 }
 
@@ -1,8 +1,18 @@
-use dsa::Components;
+// We abused the deprecated attribute for unsecure key sizes
+// But we want to use those small key sizes for fast tests
+#![allow(deprecated)]
+
+use dsa::{Components, KeySize, ValidationParms};
+use hex_literal::hex;
+use num_bigint::BigUint;
+use num_traits::Num;
 use pkcs8::{
     der::{Decode, Encode},
     Document,
 };
+use rand::{CryptoRng, RngCore};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256};
 
 const OPENSSL_PEM_COMPONENTS: &str = include_str!("pems/params.pem");
 
@@ -21,3 +31,438 @@ fn decode_encode_openssl_components() {
 
     assert_eq!(raw_components, reencoded_components);
 }
+
+#[test]
+fn generate_and_validate_verifiable_components() {
+    let mut rng = rand::thread_rng();
+    let components =
+        Components::generate_verifiable::<Sha1>(&mut rng, KeySize::DSA_1024_160, 1).unwrap();
+
+    let validation_parms = components.validation_parms().unwrap();
+    assert_eq!(validation_parms.seed().len(), 20);
+
+    assert!(components.validate_pq::<Sha1>().is_ok());
+    assert!(components.validate_g::<Sha1>(1).is_ok());
+    assert!(components.validate_g::<Sha1>(2).is_err());
+
+    // Tampering with the seed or counter invalidates p and q
+    let mut seed = validation_parms.seed().to_vec();
+    seed[0] ^= 1;
+    let tampered = components
+        .clone()
+        .with_validation_parms(ValidationParms::new(seed, validation_parms.pgen_counter()));
+    assert!(tampered.validate_pq::<Sha1>().is_err());
+
+    let tampered = components
+        .clone()
+        .with_validation_parms(ValidationParms::new(
+            validation_parms.seed().to_vec(),
+            validation_parms.pgen_counter() + 1,
+        ));
+    assert!(tampered.validate_pq::<Sha1>().is_err());
+
+    // Replacing g with a different generator of the same subgroup invalidates g
+    let g = components.g().modpow(&BigUint::from(2_u8), components.p());
+    let tampered = Components::from_components(components.p().clone(), components.q().clone(), g)
+        .unwrap()
+        .with_validation_parms(validation_parms.clone());
+    assert!(tampered.validate_pq::<Sha1>().is_ok());
+    assert!(tampered.validate_g::<Sha1>(1).is_err());
+}
+
+#[test]
+fn validate_without_validation_parms() {
+    let (_, document) =
+        Document::from_pem(OPENSSL_PEM_COMPONENTS).expect("Failed to parse components PEM");
+    let components = Components::from_der(document.as_bytes()).unwrap();
+
+    assert!(components.validation_parms().is_none());
+    assert!(components.validate_pq::<Sha1>().is_err());
+    assert!(components.validate_g::<Sha1>(1).is_err());
+}
+
+#[test]
+fn verifiable_components_require_sufficient_hash() {
+    let mut rng = rand::thread_rng();
+    assert!(Components::generate_verifiable::<Sha1>(&mut rng, KeySize::DSA_2048_224, 1).is_err());
+}
+
+#[test]
+fn decode_encode_validation_parms() {
+    let mut rng = rand::thread_rng();
+    let components =
+        Components::generate_verifiable::<Sha1>(&mut rng, KeySize::DSA_1024_160, 1).unwrap();
+
+    // The components are encoded as p, q and g only
+    let encoded = components.to_der().unwrap();
+    let decoded = Components::from_der(&encoded).unwrap();
+    let without_validation_parms = Components::from_components(
+        components.p().clone(),
+        components.q().clone(),
+        components.g().clone(),
+    )
+    .unwrap();
+    assert_eq!(encoded, without_validation_parms.to_der().unwrap());
+    assert_eq!(decoded, without_validation_parms);
+    assert!(decoded.validation_parms().is_none());
+
+    // The validation parameters are encoded separately
+    let encoded = components.validation_parms().unwrap().to_der().unwrap();
+    let decoded = decoded.with_validation_parms(ValidationParms::from_der(&encoded).unwrap());
+    assert_eq!(decoded, components);
+    assert!(decoded.validate_pq::<Sha1>().is_ok());
+    assert!(decoded.validate_g::<Sha1>(1).is_ok());
+}
+
+#[test]
+fn reject_components_with_trailing_elements() {
+    let (_, document) =
+        Document::from_pem(OPENSSL_PEM_COMPONENTS).expect("Failed to parse components PEM");
+    assert_eq!(document.as_bytes()[..2], [0x30, 0x82]);
+
+    // SEQUENCE { p, q, g, ValidationParms }
+    let validation_parms = ValidationParms::new(vec![0x42; 20], 7).to_der().unwrap();
+    let mut value = document.as_bytes()[4..].to_vec();
+    value.extend_from_slice(&validation_parms);
+
+    let mut encoded = vec![0x30, 0x82];
+    encoded.extend_from_slice(&u16::try_from(value.len()).unwrap().to_be_bytes());
+    encoded.extend_from_slice(&value);
+
+    assert!(Components::from_der(&encoded).is_err());
+}
+
+#[test]
+fn validate_components() {
+    let mut rng = rand::thread_rng();
+
+    let (_, document) =
+        Document::from_pem(OPENSSL_PEM_COMPONENTS).expect("Failed to parse components PEM");
+    let components = Components::from_der(document.as_bytes()).unwrap();
+    assert!(components.validate(&mut rng).is_ok());
+
+    let generated = Components::generate(&mut rng, KeySize::DSA_1024_160);
+    assert!(generated.validate(&mut rng).is_ok());
+
+    let (p, q, g) = (components.p(), components.q(), components.g());
+
+    // q doesn't divide p - 1
+    let other_q = generated.q().clone();
+    let tampered = Components::from_components(p.clone(), other_q, g.clone()).unwrap();
+    assert!(tampered.validate(&mut rng).is_err());
+
+    // Composite p
+    let composite = p + BigUint::from(2_u8);
+    let tampered = Components::from_components(composite, q.clone(), g.clone()).unwrap();
+    assert!(tampered.validate(&mut rng).is_err());
+
+    // g doesn't generate the subgroup of order q
+    let tampered = Components::from_components(p.clone(), q.clone(), BigUint::from(2_u8)).unwrap();
+    assert!(tampered.validate(&mut rng).is_err());
+
+    // Unapproved (L, N) pair
+    let tampered = Components::from_components(
+        generated.p().clone(),
+        BigUint::from(2_u8),
+        generated.g().clone(),
+    )
+    .unwrap();
+    assert!(tampered.validate(&mut rng).is_err());
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn decode_encode_openssl_components_pem() {
+    use pkcs8::LineEnding;
+
+    let components =
+        Components::from_pem(OPENSSL_PEM_COMPONENTS).expect("Failed to parse DSA PARAMETERS PEM");
+
+    let reencoded_components = components
+        .to_pem(LineEnding::LF)
+        .expect("Failed to encode components to PEM");
+
+    assert_eq!(reencoded_components, OPENSSL_PEM_COMPONENTS);
+}
+
+#[test]
+fn generate_components_from_seed() {
+    let seed = [0x42; 20];
+    let components = Components::generate_from_seed(&seed, KeySize::DSA_1024_160).unwrap();
+    let regenerated = Components::generate_from_seed(&seed, KeySize::DSA_1024_160).unwrap();
+    assert_eq!(components, regenerated);
+
+    assert!(components.validate_pq::<Sha1>().is_ok());
+    assert!(components.validate_g::<Sha1>(1).is_ok());
+
+    // A different seed yields different components
+    let other = Components::generate_from_seed(&[0x43; 20], KeySize::DSA_1024_160).unwrap();
+    assert_ne!(components.p(), other.p());
+
+    // The seed must be at least N bits long
+    assert!(Components::generate_from_seed(&[0x42; 19], KeySize::DSA_1024_160).is_err());
+}
+
+#[test]
+fn components_equality_ignores_validation_parms() {
+    let components = Components::generate_from_seed(&[0x42; 20], KeySize::DSA_1024_160).unwrap();
+    assert!(components.validation_parms().is_some());
+
+    let decoded = Components::from_der(&components.to_der().unwrap()).unwrap();
+    assert!(decoded.validation_parms().is_none());
+    assert_eq!(decoded, components);
+    assert_eq!(
+        decoded.partial_cmp(&components),
+        Some(core::cmp::Ordering::Equal)
+    );
+}
+
+/// Domain parameters generated with OpenSSL 3.5 following FIPS 186-4 Appendix A.1.1.2 and
+/// Appendix A.2.3 (with index 1), along with the domain parameter seed and counter it reported:
+///
+/// ```text
+/// $ openssl genpkey -genparam -algorithm DSA -text -pkeyopt type:fips186_4 \
+///     -pkeyopt pbits:L -pkeyopt qbits:N -pkeyopt digest:SHA... -pkeyopt gindex:1
+/// ```
+fn openssl_seeded_components() -> [(KeySize, &'static [u8], u32, Components); 3] {
+    let components = |p: &str, q: &str, g: &str| {
+        Components::from_components(
+            BigUint::from_str_radix(p, 16).unwrap(),
+            BigUint::from_str_radix(q, 16).unwrap(),
+            BigUint::from_str_radix(g, 16).unwrap(),
+        )
+        .unwrap()
+    };
+
+    [
+        (
+            KeySize::DSA_1024_160,
+            &hex!("7dbe276357de574b5cdab7fcc60c93cd9986c30e"),
+            5,
+            components(
+                "BAFF0B88B923821D515AADB94C046619F1543D1C592D00794B0982E04A990DE2\
+                0790D0F18635510BA0E3A8703C387BD8A6D9EED443DC17A2B8EF5ED14302494C\
+                BB02F4393F9A3FDFA1FE2A4B13C7148D4555A8030D368A47D39756D460AE7585\
+                6495CA3C9F221609A017F7A82A3DB018CAE4599644E1FD78614851CB5A30C7C3",
+                "C411FC3781F92A9C88B53D22958E463832C13DA3",
+                "38C74E6FED6670082F9612DE4E1D15F25348491880FB6D35252B201DFD076DCA\
+                B9B6FF3BFA07A50DDCA3AA290DF00BD95E66E68569189F509D33DC2A72A9016C\
+                50D468915C424227E71B40CCBF45F362EDE2D51E1EAF601157F8B3AE9AA73B9D\
+                D9EF5A2C3181CF584472686EF84A14A379D54CA1EAD6A295D538C8BEE514E997",
+            ),
+        ),
+        (
+            KeySize::DSA_2048_224,
+            &hex!("29ec0ccfbeea4de2509d9d68b82fa5fa332bb0c30db828809c00336d"),
+            24,
+            components(
+                "E6B860C41A3C34F742E0ED8E563D1B339F1615A5C678DCE56BA1A8E759E271A9\
+                41149C65CA492C1C07F4A0463B39C321C044A397C731B399469F12AFC1E59468\
+                C4E09B9880368CBB4577E69BD02F25AC586E2E9F37C0B96710601EF88B0E2254\
+                E928094FFAE5C87E59D1BB1F8D4CFDB0F459CA5E75F159D9F15DE4B3A9CC776E\
+                DD165CBAE7B2C776467B78EFA5E254A83D0A696AEC3B7855909F271463122EFA\
+                87445039A385101C9609601CF236E7DF3860357D17DD4D8197BB65B37C967777\
+                BAE69D9362375F3782F4AB1202F6A772DC748F31B671E96D45CF4154670C0435\
+                7110B38458C5D20DBDF130984FAA54EE5B2B1DCA33D7A06B420BEF29C4AEA4DD",
+                "F3F062F4EFC91A7CECE1C47D5C2C7F823C931C3C2C1592BA2D469B97",
+                "50B5EC5FBEC52EE3E8EF69B28947A704279A12A9209997A402E85ADCC55D8E7C\
+                5B3802248F8944FC51DF30C3BEB392E91BC655376783BD71558763A2524849E6\
+                56261D9A205374F0C533795AB3559D41F689ECC173BF6076CD15AEE817B4835F\
+                1F9FD06A3C5812C9DCFB3208DCAD625B0C1D5F14C761C1A3554A8A3D8FFB5D88\
+                BA451730D8FA4FB20F7FE5970F59200BCCCCFD87025CF3A7A2E3977789489336\
+                96465373656D086EA0187B740FE6A26B692A09341FE1FB4F2F47648FBA647655\
+                45BCF4D816B44F2A484B314A4EA6D997AF9E3E45457972B118C739F5DA1C7537\
+                C2F98470D9F112E3AA3BD1823973135C6327ECEC68FE8B556B25F1B6D53BCE2A",
+            ),
+        ),
+        (
+            KeySize::DSA_2048_256,
+            &hex!("c5c3144fcea66b1df251a02eec5f9431b9ab409ca457d72c765660ba28feda82"),
+            4,
+            components(
+                "D812C64C5994C7CC411709204427BD4AE64B75054AEA2F4753BB04C0863A2F6F\
+                80C4282C4C4CBAA0790584D803418A4711FC7C738811801ED987F7A4F5D9BFAF\
+                396FA0B6E1F807EA964C95BB6B2F3B0FD5C2CD80DB77C9900D1360AFCBD961DE\
+                EF97A9DEDB1C62A98A30A461D256E1C841E9C68BDDC06EB3FE45139FE526F070\
+                2669D8E3859BF529031D330587E6AB3AF6AF3020C13E54EF1D1B5E761E9161ED\
+                AF486E79631AC5627CF07F1272900A18D75EF15099A1A761A6BFDF41FD483109\
+                4BE676A99D8FB816905FE2E44920A0DD2B9CBE490DB902AEA0DACF593313F09A\
+                DBD29A6456DA84D02A2F28C25FC9B7631055F2C778ED27A5AA41697B7A23D5B1",
+                "DC8FBD9E9CD9DC0FDC7C5CDBDA37313BB363A08DDA6FE62EE73EE3F300FA2FBF",
+                "83533023C166D4E4E1E6B445CD33BB9A9444B2E04565911AAF0C31DD9DC0BABE\
+                F04DFF26B7DC0BCB576DEE86A05531497608B743743BEA4DE2FB2CE9BDD3C6D5\
+                2B39FFFCD2E07B971E04371DE2569FDEEA64F2CBBBA8351FB98186B161F820E5\
+                1E0254EE5C668544DDA8DAF4FDE332EAEC8BEE4094B09D271EBA762763D2F6F9\
+                904347FE0A5FA532B9BD6C8BBDB1D6CD3F0197C0CB1D28B4113278ABFC6F70B3\
+                21E7865079DD1FCE860F3662EFC3F4417B89213FC53ECA7A8910A69D75C0803D\
+                F5331B6DBC998F20B8B9729CA10DED9CA1710C692651D47F5584774B536D69F9\
+                FCF6F4D77F46AAC8A1AB2BF78D5D710FFF77FE437B93945FA20FEB1AD884D4AF",
+            ),
+        ),
+    ]
+}
+
+#[test]
+fn generate_components_from_seed_openssl() {
+    for (key_size, seed, counter, expected) in openssl_seeded_components() {
+        let components = Components::generate_from_seed(seed, key_size).unwrap();
+        assert_eq!(components.p(), expected.p());
+        assert_eq!(components.q(), expected.q());
+        assert_eq!(components.g(), expected.g());
+
+        let validation_parms = components.validation_parms().unwrap();
+        assert_eq!(validation_parms.seed(), seed);
+        assert_eq!(validation_parms.pgen_counter(), counter);
+    }
+}
+
+/// RNG which returns a fixed domain parameter seed, so that verifiable generation is reproducible
+struct FixedSeed<'a>(&'a [u8]);
+
+impl RngCore for FixedSeed<'_> {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(self.0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for FixedSeed<'_> {}
+
+#[test]
+fn generate_verifiable_components_openssl() {
+    let [(key_size, seed, counter, expected), ..] = openssl_seeded_components();
+    let components =
+        Components::generate_verifiable::<Sha1>(&mut FixedSeed(seed), key_size, 1).unwrap();
+    assert_eq!(components.p(), expected.p());
+    assert_eq!(components.q(), expected.q());
+    assert_eq!(components.g(), expected.g());
+
+    let validation_parms = components.validation_parms().unwrap();
+    assert_eq!(validation_parms.seed(), seed);
+    assert_eq!(validation_parms.pgen_counter(), counter);
+
+    // Index 2, generated with `-pkeyopt hexseed:... -pkeyopt gindex:2`
+    let g = BigUint::from_str_radix(
+        "42E7B25ECBD7961B6CDD1438543753CA03A13763A0147D3C5D05503127444E36\
+        F0CED309709982C2D9F91B9CDF0EB82060C29DBCD5946638A156A02B17618C7E\
+        1C435114D1BC00665D2325A900C2C51E33F74665B53E5E5CEA3F065C8D16FE9C\
+        66A0FC456A3A184ABD728D7EC62F40EC4CF41EA4B3D74A09306A7C5F86553326",
+        16,
+    )
+    .unwrap();
+    let components =
+        Components::generate_verifiable::<Sha1>(&mut FixedSeed(seed), KeySize::DSA_1024_160, 2)
+            .unwrap();
+    assert_eq!(components.p(), expected.p());
+    assert_eq!(*components.g(), g);
+    assert!(components.validate_g::<Sha1>(2).is_ok());
+    assert!(components.validate_g::<Sha1>(1).is_err());
+}
+
+#[test]
+fn validate_openssl_components() {
+    let [sha1, sha224, sha256] =
+        openssl_seeded_components().map(|(_, seed, counter, components)| {
+            components.with_validation_parms(ValidationParms::new(seed.to_vec(), counter))
+        });
+
+    assert!(sha1.validate_pq::<Sha1>().is_ok());
+    assert!(sha1.validate_g::<Sha1>(1).is_ok());
+    assert!(sha224.validate_pq::<Sha224>().is_ok());
+    assert!(sha224.validate_g::<Sha224>(1).is_ok());
+    assert!(sha256.validate_pq::<Sha256>().is_ok());
+    assert!(sha256.validate_g::<Sha256>(1).is_ok());
+
+    // Wrong hash function
+    assert!(sha224.validate_pq::<Sha256>().is_err());
+    assert!(sha256.validate_pq::<Sha224>().is_err());
+    assert!(sha256.validate_g::<Sha224>(1).is_err());
+
+    // Wrong index
+    assert!(sha256.validate_g::<Sha256>(2).is_err());
+
+    for (components, validate_pq) in [
+        (
+            &sha1,
+            Components::validate_pq::<Sha1> as fn(&Components) -> _,
+        ),
+        (&sha224, Components::validate_pq::<Sha224>),
+        (&sha256, Components::validate_pq::<Sha256>),
+    ] {
+        let validation_parms = components.validation_parms().unwrap();
+        let (seed, counter) = (validation_parms.seed(), validation_parms.pgen_counter());
+
+        // Wrong counter
+        for counter in [counter - 1, counter + 1] {
+            let tampered = components
+                .clone()
+                .with_validation_parms(ValidationParms::new(seed.to_vec(), counter));
+            assert!(validate_pq(&tampered).is_err());
+        }
+
+        // Wrong seed
+        let mut tampered_seed = seed.to_vec();
+        *tampered_seed.last_mut().unwrap() ^= 1;
+        let tampered = components
+            .clone()
+            .with_validation_parms(ValidationParms::new(tampered_seed, counter));
+        assert!(validate_pq(&tampered).is_err());
+    }
+
+    // p and q of one set combined with the validation parameters of another
+    let tampered =
+        Components::from_components(sha224.p().clone(), sha224.q().clone(), sha224.g().clone())
+            .unwrap()
+            .with_validation_parms(sha256.validation_parms().unwrap().clone());
+    assert!(tampered.validate_pq::<Sha256>().is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn generate_components_in_parallel() {
+    use std::{
+        num::NonZeroUsize,
+        ops::ControlFlow,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    let mut rng = rand::thread_rng();
+    let threads = NonZeroUsize::new(4).unwrap();
+
+    let reports = AtomicU64::new(0);
+    let components =
+        Components::generate_parallel(&mut rng, KeySize::DSA_1024_160, threads, |progress| {
+            assert!(progress.q_candidates() >= 1);
+            reports.fetch_add(1, Ordering::Relaxed);
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    assert_eq!(components.p().bits(), 1024);
+    assert_eq!(components.q().bits(), 160);
+    assert!(components.validate(&mut rng).is_ok());
+    assert!(reports.load(Ordering::Relaxed) >= 2);
+
+    // Cancel once a few candidates for p have been tested
+    let cancelled =
+        Components::generate_parallel(&mut rng, KeySize::DSA_2048_256, threads, |progress| {
+            if progress.p_candidates() >= 8 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+    assert!(cancelled.is_err());
+}
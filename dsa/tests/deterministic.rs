@@ -1,10 +1,23 @@
 use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset};
-use dsa::{Components, Signature, SigningKey, VerifyingKey};
+use dsa::{
+    Components, P1363Signature, Signature, SignatureWithOid, SigningKey, VerifyingKey,
+    DSA_SHA1_OID, DSA_SHA224_OID, DSA_SHA256_OID, OID,
+};
 use num_bigint::BigUint;
 use num_traits::Num;
+use pkcs8::{
+    der::Encode,
+    spki::{
+        DynAssociatedAlgorithmIdentifier, DynSignatureAlgorithmIdentifier,
+        SignatureBitStringEncoding,
+    },
+};
+use rand::{CryptoRng, RngCore};
 use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512};
-use signature::DigestSigner;
+use signature::{
+    hazmat::PrehashSigner, DigestSigner, DigestVerifier, SignatureEncoding, Signer, Verifier,
+};
 
 const MESSAGE: &[u8] = b"sample";
 const MESSAGE_2: &[u8] = b"test";
@@ -312,3 +325,219 @@ fn rfc6979_signatures() {
         assert_eq!(expected, gen_fn(), "{}th test case", idx);
     }
 }
+
+#[test]
+fn signer_uses_key_size_digest() {
+    let signing_key = dsa_2048_signing_key();
+
+    // sha256, 2048, "sample"
+    let expected = from_str_signature(
+        "EACE8BDBBE353C432A795D9EC556C6D021F7A03F42C36E9BC87E4AC7932CC809",
+        "7081E175455F9247B812B74583E9E94F9EA79BD640DC962533B0680793A38D53",
+    );
+
+    let signature: Signature = signing_key.sign(MESSAGE);
+    assert_eq!(signature, expected);
+    assert!(signing_key
+        .verifying_key()
+        .verify(MESSAGE, &signature)
+        .is_ok());
+
+    // 1024/160 keys are approved for verification only
+    assert!(Signer::<Signature>::try_sign(&dsa_1024_signing_key(), MESSAGE).is_err());
+}
+
+#[test]
+fn sign_with_digest() {
+    let signing_key = dsa_2048_signing_key();
+
+    // sha224, 2048, "sample"
+    let expected = from_str_signature(
+        "DC9F4DEADA8D8FF588E98FED0AB690FFCE858DC8C79376450EB6B76C24537E2C",
+        "A65A9C3BC7BABE286B195D5DA68616DA8D47FA0097F36DD19F517327DC848CEC",
+    );
+    assert_eq!(signing_key.sign_with::<Sha224>(MESSAGE).unwrap(), expected);
+
+    let prehash = Sha224::digest(MESSAGE);
+    assert_eq!(
+        signing_key
+            .sign_prehash_with_digest::<Sha224>(&prehash)
+            .unwrap(),
+        expected
+    );
+
+    // The `HMAC_DRBG` digest is inferred from the length of the prehash
+    assert_eq!(signing_key.sign_prehash(&prehash).unwrap(), expected);
+
+    // sha384, 2048, "test"
+    let expected = from_str_signature(
+        "239E66DDBE8F8C230A3D071D601B6FFBDFB5901F94D444C6AF56F732BEB954BE",
+        "6BD737513D5E72FE85D1C750E0F73921FE299B945AAD1C802F15C26A43D34961",
+    );
+    let prehash = Sha384::digest(MESSAGE_2);
+    assert_eq!(signing_key.sign_prehash(&prehash).unwrap(), expected);
+
+    // SHA-1 prehashes are approved for verification only
+    assert!(signing_key.sign_prehash(&Sha1::digest(MESSAGE)).is_err());
+}
+
+#[test]
+fn signature_with_oid() {
+    let signing_key = dsa_2048_signing_key();
+    let verifying_key = signing_key.verifying_key();
+
+    // sha256, 2048, "sample"
+    let expected = from_str_signature(
+        "EACE8BDBBE353C432A795D9EC556C6D021F7A03F42C36E9BC87E4AC7932CC809",
+        "7081E175455F9247B812B74583E9E94F9EA79BD640DC962533B0680793A38D53",
+    );
+
+    let signature: SignatureWithOid = signing_key.sign(MESSAGE);
+    assert_eq!(signature.oid(), DSA_SHA256_OID);
+    assert_eq!(*signature.signature(), expected);
+    assert!(verifying_key.verify(MESSAGE, &signature).is_ok());
+
+    let algorithm = signature.algorithm_identifier().unwrap();
+    assert_eq!(algorithm.oid, DSA_SHA256_OID);
+    assert!(algorithm.parameters.is_none());
+    assert_eq!(
+        signing_key.signature_algorithm_identifier().unwrap(),
+        algorithm
+    );
+
+    let bitstring = signature.to_bitstring().unwrap();
+    assert_eq!(bitstring.raw_bytes(), expected.to_der().unwrap());
+
+    // sha224, 2048, "sample"
+    let signature: SignatureWithOid = signing_key.sign_digest(Sha224::new_with_prefix(MESSAGE));
+    assert_eq!(signature.oid(), DSA_SHA224_OID);
+    assert!(verifying_key.verify(MESSAGE, &signature).is_ok());
+
+    // A signature whose OID names another digest doesn't verify
+    let mislabeled = SignatureWithOid::new(signature.signature().clone(), DSA_SHA1_OID).unwrap();
+    assert!(verifying_key.verify(MESSAGE, &mislabeled).is_err());
+
+    // Only DSA signature algorithm OIDs are accepted
+    assert!(SignatureWithOid::new(expected, OID).is_err());
+
+    // 1024/160 keys only sign with an explicitly chosen digest
+    let signing_key = dsa_1024_signing_key();
+    let verifying_key = signing_key.verifying_key();
+    assert!(signing_key.signature_algorithm_identifier().is_err());
+    assert!(Signer::<SignatureWithOid>::try_sign(&signing_key, MESSAGE).is_err());
+
+    let signature: SignatureWithOid = signing_key.sign_digest(Sha1::new_with_prefix(MESSAGE));
+    assert_eq!(signature.oid(), DSA_SHA1_OID);
+    assert!(verifying_key.verify(MESSAGE, &signature).is_ok());
+}
+
+#[test]
+fn p1363_signature() {
+    let signing_key = dsa_2048_signing_key();
+    let verifying_key = signing_key.verifying_key();
+
+    // sha256, 2048, "sample"
+    let expected = hex_literal::hex!(
+        "EACE8BDBBE353C432A795D9EC556C6D021F7A03F42C36E9BC87E4AC7932CC809"
+        "7081E175455F9247B812B74583E9E94F9EA79BD640DC962533B0680793A38D53"
+    );
+
+    let signature: P1363Signature = signing_key.sign(MESSAGE);
+    assert_eq!(signature.q_len(), 32);
+    assert_eq!(*signature.to_bytes(), expected);
+    assert!(verifying_key.verify(MESSAGE, &signature).is_ok());
+
+    let decoded = P1363Signature::try_from(&expected[..]).unwrap();
+    assert_eq!(decoded, signature);
+    assert_eq!(
+        Signature::from_p1363(&expected, 32).unwrap(),
+        *signature.signature()
+    );
+
+    // Strict length checks
+    assert!(P1363Signature::try_from(&expected[1..]).is_err());
+    assert!(Signature::from_p1363(&expected[2..], 32).is_err());
+    assert!(signature.signature().to_p1363(31).is_err());
+
+    // Over-padded signatures are rejected by the verifier
+    let padded = P1363Signature::new(signature.signature().clone(), 33).unwrap();
+    assert_eq!(padded.to_bytes().len(), 66);
+    assert!(verifying_key.verify(MESSAGE, &padded).is_err());
+
+    // sha1, 1024, "sample"
+    let signing_key = dsa_1024_signing_key();
+    let signature: P1363Signature = signing_key.sign_digest(Sha1::new_with_prefix(MESSAGE));
+    assert_eq!(
+        *signature.to_bytes(),
+        hex_literal::hex!(
+            "2E1A0C2562B2912CAAF89186FB0F42001585DA55"
+            "29EFB6B0AFF2D7A68EB70CA313022253B9A88DF5"
+        )
+    );
+    assert!(signing_key
+        .verifying_key()
+        .verify_digest(Sha1::new_with_prefix(MESSAGE), &signature)
+        .is_ok());
+}
+
+/// RNG which fills every buffer with the same byte, so that hedged signatures are reproducible
+struct ConstRng(u8);
+
+impl RngCore for ConstRng {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(self.0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ConstRng {}
+
+#[test]
+fn private_key_with_leading_zero_byte() {
+    // x is shorter than q, and must be left-padded to the length of q when seeding `HMAC_DRBG`
+    let components = dsa_2048_signing_key().verifying_key().components().clone();
+    let x = BigUint::from_str_radix(
+        "0069C7548C21D0DFEA6B9A51C9EAD4E27C33D3B3F180316E5BCAB92C933F0E4D",
+        16,
+    )
+    .unwrap();
+    let y = components.g().modpow(&x, components.p());
+    let verifying_key = VerifyingKey::from_components(components, y).unwrap();
+    let signing_key = SigningKey::from_components(verifying_key, x).unwrap();
+
+    // sha256, 2048, "sample"
+    let expected = from_str_signature(
+        "BEDA8743B3CF5CF7197589E2CEB8AB3A2AE8A76B162AF4CE76DC2140BC4280EF",
+        "E4198B18026A83A4F6503ED0908C8B7E1A78BDE1644915C419C49F13B778E3A3",
+    );
+    assert_eq!(
+        generate_signature::<Sha256>(signing_key.clone(), MESSAGE),
+        expected
+    );
+
+    // sha256, 2048, "sample", with 32 bytes of 0x01 as the hedging randomness
+    let expected = from_str_signature(
+        "D6AC6E736C3B27836E15023864A0E0CC2154FD5B681E1ADBCABBC46861A566F5",
+        "21348D746D200C8940722EDB70CB3973B42252C58B69D044C800A9A443E0E4E9",
+    );
+    let signature = signing_key
+        .try_sign_digest_hedged(&mut ConstRng(1), Sha256::new_with_prefix(MESSAGE))
+        .unwrap();
+    assert_eq!(signature, expected);
+    assert!(signing_key
+        .verifying_key()
+        .verify_digest(Sha256::new_with_prefix(MESSAGE), &signature)
+        .is_ok());
+}
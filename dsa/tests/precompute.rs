@@ -0,0 +1,58 @@
+// We abused the deprecated attribute for unsecure key sizes
+// But we want to use those small key sizes for fast tests
+#![allow(deprecated)]
+
+use digest::Digest;
+use dsa::{Components, KeySize, Signature, SigningKey};
+use pkcs8::DecodePrivateKey;
+use sha2::Sha256;
+use signature::{DigestVerifier, Signer, Verifier};
+
+const OPENSSL_PEM_PRIVATE_KEY: &str = include_str!("pems/private.pem");
+
+#[test]
+fn precomputed_signing_key_matches() {
+    let signing_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+    let precomputed = signing_key.clone().with_precomputation();
+    assert_eq!(signing_key, precomputed);
+
+    for msg in [&b"hello"[..], b"world", b""] {
+        // Deterministic signatures are identical with and without the tables
+        let signature: Signature = signing_key.sign(msg);
+        let precomputed_signature: Signature = precomputed.sign(msg);
+        assert_eq!(signature, precomputed_signature);
+
+        assert!(precomputed.verifying_key().verify(msg, &signature).is_ok());
+        assert!(precomputed
+            .verifying_key()
+            .verify(b"other", &signature)
+            .is_err());
+    }
+}
+
+#[test]
+fn precomputed_components_only() {
+    let mut rng = rand::thread_rng();
+    let components = Components::generate(&mut rng, KeySize::DSA_1024_160);
+    let signing_key = SigningKey::generate(&mut rng, components.clone().with_precomputation());
+
+    // Only g has a table, y is raised by multi-exponentiation
+    let signature = signing_key.sign_with::<Sha256>(b"hello").unwrap();
+    assert!(signing_key
+        .verifying_key()
+        .verify_digest(Sha256::new_with_prefix(b"hello"), &signature)
+        .is_ok());
+
+    let plain_key = SigningKey::from_components(
+        dsa::VerifyingKey::from_components(components, signing_key.verifying_key().y().clone())
+            .unwrap(),
+        signing_key.x().clone(),
+    )
+    .unwrap();
+    let plain_signature = plain_key.sign_with::<Sha256>(b"hello").unwrap();
+    assert_eq!(signature, plain_signature);
+    assert!(plain_key
+        .verifying_key()
+        .verify_digest(Sha256::new_with_prefix(b"hello"), &signature)
+        .is_ok());
+}
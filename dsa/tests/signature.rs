@@ -5,9 +5,10 @@ use dsa::{Components, KeySize, Signature, SigningKey};
 use pkcs8::der::{Decode, Encode};
 use rand::{CryptoRng, RngCore, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use sha1::Sha1;
 use sha2::Sha256;
 use signature::{
-    hazmat::{PrehashSigner, PrehashVerifier},
+    hazmat::{PrehashSigner, PrehashVerifier, RandomizedPrehashSigner},
     DigestVerifier, RandomizedDigestSigner, Signer, Verifier,
 };
 
@@ -97,15 +98,23 @@ fn signer_verifier_signature() {
     let verifying_key = signing_key.verifying_key();
     let message = b"Hello world! This is the message signed as part of the testing process.";
 
-    // construct signature manually and by `Signer` defaults. Ensure results are identical.
-    let manual_digest = Sha256::new_with_prefix(message).finalize();
-    let manual_signature = signing_key.sign_prehash(&manual_digest).unwrap();
-    let signer_signature = signing_key.sign(message);
-    verifying_key.verify(message, &manual_signature).unwrap();
-    verifying_key.verify(message, &signer_signature).unwrap();
+    // 1024/160 keys are approved for verification only, so `Signer` and `PrehashSigner`
+    // refuse to sign
+    let manual_digest = Sha1::new_with_prefix(message).finalize();
+    assert!(Signer::<Signature>::try_sign(&signing_key, message).is_err());
+    assert!(signing_key.sign_prehash(&manual_digest).is_err());
+    assert!(signing_key
+        .sign_prehash_with_rng(&mut rand::thread_rng(), &manual_digest)
+        .is_err());
+
+    // construct signature manually and by `sign_with`. Ensure results are identical.
+    let manual_signature = signing_key
+        .sign_prehash_with_digest::<Sha1>(&manual_digest)
+        .unwrap();
+    let signer_signature = signing_key.sign_with::<Sha1>(message).unwrap();
     assert_eq!(manual_signature, signer_signature);
 
-    // verify signature manually and by `Verifier` defaults. Ensure signatures can be applied interchangeably.
+    // verify signature manually and by `Verifier` defaults (SHA-1 for 1024/160 keys). Ensure signatures can be applied interchangeably.
     verifying_key
         .verify_prehash(&manual_digest, &manual_signature)
         .unwrap();
@@ -3,10 +3,15 @@
 #![allow(deprecated)]
 
 use digest::Digest;
-use dsa::{Components, KeySize, SigningKey};
+use dsa::{Components, KeySize, SigningKey, VerifyingKey};
+use hex_literal::hex;
 use num_bigint::BigUint;
-use num_traits::Zero;
-use pkcs8::{DecodePrivateKey, EncodePrivateKey, LineEnding};
+use num_traits::{Num, Zero};
+use pkcs8::{
+    der::{asn1::UintRef, Encode},
+    DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding,
+    PrivateKeyInfo,
+};
 use sha1::Sha1;
 use signature::{DigestVerifier, RandomizedDigestSigner};
 
@@ -54,6 +59,29 @@ fn sign_and_verify() {
         .is_ok());
 }
 
+#[test]
+fn sign_and_verify_hedged() {
+    const DATA: &[u8] = b"SIGN AND VERIFY THOSE BYTES";
+
+    let signing_key = generate_keypair();
+    let verifying_key = signing_key.verifying_key();
+
+    let signature1 = signing_key
+        .try_sign_digest_hedged(&mut rand::thread_rng(), Sha1::new().chain_update(DATA))
+        .unwrap();
+    let signature2 = signing_key
+        .try_sign_digest_hedged(&mut rand::thread_rng(), Sha1::new().chain_update(DATA))
+        .unwrap();
+
+    assert_ne!(signature1, signature2);
+
+    for signature in [signature1, signature2] {
+        assert!(verifying_key
+            .verify_digest(Sha1::new().chain_update(DATA), &signature)
+            .is_ok());
+    }
+}
+
 #[test]
 fn verify_validity() {
     let signing_key = generate_keypair();
@@ -69,3 +97,208 @@ fn verify_validity() {
         "Requirement y=(g^x)%p not met"
     );
 }
+
+#[test]
+fn decode_validated_signing_key() {
+    let mut rng = rand::thread_rng();
+    let signing_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+    let der = signing_key.to_pkcs8_der().unwrap();
+
+    let validated = SigningKey::from_pkcs8_der_validated(&mut rng, der.as_bytes())
+        .expect("Failed to decode and validate OpenSSL private key");
+    assert_eq!(validated, signing_key);
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn decode_encode_openssl_traditional_signing_key() {
+    const OPENSSL_PEM_TRADITIONAL_PRIVATE_KEY: &str = include_str!("pems/private_traditional.pem");
+
+    let signing_key = SigningKey::from_traditional_pem(OPENSSL_PEM_TRADITIONAL_PRIVATE_KEY)
+        .expect("Failed to decode PEM encoded OpenSSL traditional key");
+
+    let pkcs8_signing_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+    assert_eq!(signing_key, pkcs8_signing_key);
+
+    let reencoded_signing_key = signing_key
+        .to_traditional_pem(LineEnding::LF)
+        .expect("Failed to encode private key into traditional PEM representation");
+    assert_eq!(*reencoded_signing_key, OPENSSL_PEM_TRADITIONAL_PRIVATE_KEY);
+
+    // The PKCS#8 label is rejected
+    assert!(SigningKey::from_traditional_pem(OPENSSL_PEM_PRIVATE_KEY).is_err());
+}
+
+#[test]
+fn encode_decode_traditional_signing_key() {
+    let signing_key = generate_keypair();
+    let encoded_signing_key = signing_key.to_traditional_der().unwrap();
+    let decoded_signing_key = SigningKey::from_traditional_der(encoded_signing_key.as_bytes())
+        .expect("Failed to decode traditional DER encoded key");
+
+    assert_eq!(signing_key, decoded_signing_key);
+
+    // Only version 0 is defined
+    let mut encoded_signing_key = encoded_signing_key.as_bytes().to_vec();
+    let version_offset = encoded_signing_key
+        .windows(3)
+        .position(|window| window == [0x02, 0x01, 0x00])
+        .unwrap();
+    encoded_signing_key[version_offset + 2] = 1;
+    assert!(SigningKey::from_traditional_der(&encoded_signing_key).is_err());
+}
+
+#[test]
+fn derive_signing_key_from_seed() {
+    let components = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY)
+        .unwrap()
+        .verifying_key()
+        .components()
+        .clone();
+
+    // N + 64 = 320 bits
+    let seed = [0x5a; 40];
+    let signing_key = SigningKey::from_seed(components.clone(), &seed).unwrap();
+    let rederived = SigningKey::from_seed(components.clone(), &seed).unwrap();
+    assert_eq!(signing_key, rederived);
+
+    let q_minus_one = components.q() - BigUint::from(1_u8);
+    let expected_x = BigUint::from_bytes_be(&seed) % q_minus_one + BigUint::from(1_u8);
+    assert_eq!(*signing_key.x(), expected_x);
+    assert_eq!(
+        *signing_key.verifying_key().y(),
+        components.g().modpow(&expected_x, components.p())
+    );
+
+    assert!(SigningKey::from_seed(components, &seed[..39]).is_err());
+}
+
+#[test]
+fn seeded_signing_key_roundtrip() {
+    let components = Components::generate_from_seed(&[0x42; 20], KeySize::DSA_1024_160).unwrap();
+    let signing_key = SigningKey::from_seed(components, &[0x5a; 40]).unwrap();
+
+    let decoded =
+        SigningKey::from_pkcs8_der(signing_key.to_pkcs8_der().unwrap().as_bytes()).unwrap();
+    assert_eq!(decoded, signing_key);
+    assert_eq!(decoded.verifying_key(), signing_key.verifying_key());
+
+    let verifying_key = signing_key.verifying_key();
+    let decoded =
+        VerifyingKey::from_public_key_der(verifying_key.to_public_key_der().unwrap().as_bytes())
+            .unwrap();
+    assert_eq!(decoded, *verifying_key);
+}
+
+/// Keypair derived with the extra random bits method of FIPS 186-4 Appendix B.1.1 from a fixed
+/// seed, using 1024/160 domain parameters generated by OpenSSL 3.5
+///
+/// The expected x and y were computed independently and checked with `openssl pkey -check`
+#[test]
+fn derive_signing_key_from_seed_known_answer() {
+    let p = BigUint::from_str_radix(
+        "BAFF0B88B923821D515AADB94C046619F1543D1C592D00794B0982E04A990DE2\
+        0790D0F18635510BA0E3A8703C387BD8A6D9EED443DC17A2B8EF5ED14302494C\
+        BB02F4393F9A3FDFA1FE2A4B13C7148D4555A8030D368A47D39756D460AE7585\
+        6495CA3C9F221609A017F7A82A3DB018CAE4599644E1FD78614851CB5A30C7C3",
+        16,
+    )
+    .unwrap();
+    let q = BigUint::from_str_radix("C411FC3781F92A9C88B53D22958E463832C13DA3", 16).unwrap();
+    let g = BigUint::from_str_radix(
+        "38C74E6FED6670082F9612DE4E1D15F25348491880FB6D35252B201DFD076DCA\
+        B9B6FF3BFA07A50DDCA3AA290DF00BD95E66E68569189F509D33DC2A72A9016C\
+        50D468915C424227E71B40CCBF45F362EDE2D51E1EAF601157F8B3AE9AA73B9D\
+        D9EF5A2C3181CF584472686EF84A14A379D54CA1EAD6A295D538C8BEE514E997",
+        16,
+    )
+    .unwrap();
+    let components = Components::from_components(p, q, g).unwrap();
+
+    let seed = hex!(
+        "101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f3031323334353637"
+    );
+    let signing_key = SigningKey::from_seed(components, &seed).unwrap();
+
+    let x = BigUint::from_str_radix("1D411CAC768A74AF40FD8ABF2B4EA182DDDC71F0", 16).unwrap();
+    let y = BigUint::from_str_radix(
+        "71EC4FBD6C32AC5DFBF53FB26E560F391DE032D1EE92537F24AE1BB2E03BCEC3\
+        6074E9FF39EB3F563E57544A1DEFDF48915A9DD30031AE0489053CBA4A98FB04\
+        B8C43B8ACD98B7ACC3F73B4186405EAB81B2E9E817C74F061598DD4F2D38024E\
+        2221F1906ACAD8017B2082861230DE37463614EE06CBC807DC048DF6BCE00504",
+        16,
+    )
+    .unwrap();
+    assert_eq!(*signing_key.x(), x);
+    assert_eq!(*signing_key.verifying_key().y(), y);
+}
+
+#[test]
+fn check_embedded_public_key() {
+    let signing_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+    let pkcs8_der = signing_key.to_pkcs8_der().unwrap();
+    let private_key_info = PrivateKeyInfo::try_from(pkcs8_der.as_bytes()).unwrap();
+
+    let encode_with_public_key = |y: &BigUint| {
+        let y_bytes = y.to_bytes_be();
+        let y_der = UintRef::new(&y_bytes).unwrap().to_der().unwrap();
+
+        let mut private_key_info = private_key_info.clone();
+        private_key_info.public_key = Some(&y_der);
+        private_key_info.to_der().unwrap()
+    };
+
+    // A matching public key is accepted
+    let der = encode_with_public_key(signing_key.verifying_key().y());
+    assert_eq!(SigningKey::from_pkcs8_der(&der).unwrap(), signing_key);
+
+    // g passes the checks on y on its own, but doesn't match x
+    let der = encode_with_public_key(signing_key.verifying_key().components().g());
+    assert!(SigningKey::from_pkcs8_der(&der).is_err());
+}
+
+#[test]
+fn pairwise_consistency_check() {
+    let signing_key = generate_keypair();
+    assert!(signing_key.pairwise_consistency_check().is_ok());
+
+    // Pair the public key with a different private component
+    let verifying_key = signing_key.verifying_key().clone();
+    let x = signing_key.x() + BigUint::from(1_u8);
+    let inconsistent = SigningKey::from_components(verifying_key, x).unwrap();
+    assert!(inconsistent.pairwise_consistency_check().is_err());
+
+    let der = inconsistent.to_traditional_der().unwrap();
+    assert!(SigningKey::from_traditional_der(der.as_bytes()).is_err());
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decode_encode_encrypted_signing_key() {
+    // Generated with:
+    // $ openssl pkcs8 -topk8 -in tests/pems/private.pem -scrypt -passout pass:hunter42
+    const OPENSSL_PEM_SCRYPT: &str = include_str!("pems/private_encrypted_scrypt.pem");
+
+    // Generated with:
+    // $ openssl pkcs8 -topk8 -in tests/pems/private.pem -v2 aes-256-cbc \
+    //     -v2prf hmacWithSHA256 -iter 2048 -passout pass:hunter42
+    const OPENSSL_PEM_PBKDF2: &str = include_str!("pems/private_encrypted_pbkdf2.pem");
+
+    let signing_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+
+    for pem in [OPENSSL_PEM_SCRYPT, OPENSSL_PEM_PBKDF2] {
+        let decrypted = SigningKey::from_pkcs8_encrypted_pem(pem, "hunter42")
+            .expect("Failed to decrypt OpenSSL encrypted private key");
+        assert_eq!(decrypted, signing_key);
+
+        assert!(SigningKey::from_pkcs8_encrypted_pem(pem, "hunter43").is_err());
+    }
+
+    let mut rng = rand::thread_rng();
+    let encrypted = signing_key
+        .to_pkcs8_encrypted_pem(&mut rng, "hunter42", LineEnding::LF)
+        .unwrap();
+    let decrypted = SigningKey::from_pkcs8_encrypted_pem(&encrypted, "hunter42").unwrap();
+    assert_eq!(decrypted, signing_key);
+}
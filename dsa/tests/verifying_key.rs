@@ -2,10 +2,12 @@
 // But we want to use those small key sizes for fast tests
 #![allow(deprecated)]
 
-use dsa::{Components, KeySize, SigningKey, VerifyingKey};
+use digest::Digest;
+use dsa::{Components, KeySize, Signature, SigningKey, VerifyingKey};
 use num_bigint::BigUint;
 use num_traits::One;
-use pkcs8::{DecodePublicKey, EncodePublicKey, LineEnding};
+use pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePublicKey, LineEnding};
+use sha2::Sha256;
 
 const OPENSSL_PEM_PUBLIC_KEY: &str = include_str!("pems/public.pem");
 
@@ -47,3 +49,92 @@ fn validate_verifying_key() {
     // Taken from the parameter validation from bouncy castle
     assert_eq!(verifying_key.y().modpow(q, p), BigUint::one());
 }
+
+#[test]
+fn decode_validated_verifying_key() {
+    let mut rng = rand::thread_rng();
+
+    let verifying_key = VerifyingKey::from_public_key_pem(OPENSSL_PEM_PUBLIC_KEY).unwrap();
+    assert!(verifying_key.validate().is_ok());
+
+    let der = verifying_key.to_public_key_der().unwrap();
+    let validated = VerifyingKey::from_public_key_der_validated(&mut rng, der.as_bytes())
+        .expect("Failed to decode and validate OpenSSL public key");
+    assert_eq!(validated, verifying_key);
+
+    // y >= p is rejected
+    let components = verifying_key.components();
+    let y = verifying_key.y() + components.p();
+    let tampered = VerifyingKey::from_components(components.clone(), y).unwrap();
+    assert!(tampered.validate().is_err());
+
+    // A public key whose components contain a composite q is rejected
+    let tampered = Components::from_components(
+        components.p().clone(),
+        components.q() * BigUint::from(3_u8),
+        components.g().clone(),
+    )
+    .unwrap();
+    let tampered = VerifyingKey::from_components(tampered, verifying_key.y().clone()).unwrap();
+    assert!(tampered.validate().is_ok());
+
+    let der = tampered.to_public_key_der().unwrap();
+    assert!(VerifyingKey::from_public_key_der_validated(&mut rng, der.as_bytes()).is_err());
+}
+
+#[test]
+fn verify_batch() {
+    const OPENSSL_PEM_PRIVATE_KEY: &str = include_str!("pems/private.pem");
+
+    let mut rng = rand::thread_rng();
+    let openssl_key = SigningKey::from_pkcs8_pem(OPENSSL_PEM_PRIVATE_KEY).unwrap();
+    let components = Components::generate(&mut rng, KeySize::DSA_1024_160);
+    let generated_keys = [
+        SigningKey::generate(&mut rng, components.clone()),
+        SigningKey::generate(&mut rng, components),
+    ];
+
+    let prehashes: Vec<[u8; 32]> = (0..6_u8).map(|i| Sha256::digest([i]).into()).collect();
+    let signing_keys = [
+        &openssl_key,
+        &generated_keys[0],
+        &generated_keys[1],
+        &openssl_key,
+        &generated_keys[0],
+        &openssl_key,
+    ];
+    let signatures: Vec<Signature> = signing_keys
+        .iter()
+        .zip(&prehashes)
+        .map(|(signing_key, prehash)| {
+            signing_key
+                .sign_prehash_with_digest::<Sha256>(prehash)
+                .unwrap()
+        })
+        .collect();
+
+    let items: Vec<(&VerifyingKey, &[u8], &Signature)> = signing_keys
+        .iter()
+        .zip(&prehashes)
+        .zip(&signatures)
+        .map(|((signing_key, prehash), signature)| {
+            (signing_key.verifying_key(), &prehash[..], signature)
+        })
+        .collect();
+
+    let batch = VerifyingKey::verify_batch(&items);
+    assert!(batch.is_valid());
+    assert!(batch.result().is_ok());
+    assert!(VerifyingKey::verify_batch(&[]).is_valid());
+
+    // Swap the prehashes of two items and use a signature from another key for a third
+    let mut tampered = items.clone();
+    tampered[1].1 = &prehashes[4];
+    tampered[4].1 = &prehashes[1];
+    tampered[5].2 = &signatures[2];
+
+    let batch = VerifyingKey::verify_batch(&tampered);
+    assert!(!batch.is_valid());
+    assert!(batch.result().is_err());
+    assert_eq!(batch.failed(), &[1, 4, 5]);
+}
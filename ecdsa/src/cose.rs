@@ -0,0 +1,640 @@
+//! CBOR Object Signing and Encryption (COSE) support.
+//!
+//! This module provides:
+//!
+//! - [`COSE_Key`][RFC9052 Section 7] import and export for [`VerifyingKey`]
+//!   and [`SigningKey`], using the `EC2` key type
+//! - signing and verification of [`COSE_Sign1`][RFC9052 Section 4.2]
+//!   structures, using the algorithm returned by [`algorithm`]
+//!
+//! The curve is identified by its [`JwkParameters::CRV`], and must be one of
+//! the curves registered for COSE: P-256, P-384, P-521, or secp256k1.
+//!
+//! [RFC9052 Section 4.2]: https://www.rfc-editor.org/rfc/rfc9052#section-4.2
+//! [RFC9052 Section 7]: https://www.rfc-editor.org/rfc/rfc9052#section-7
+
+use crate::{
+    hazmat::{DigestPrimitive, VerifyPrimitive},
+    Error, Result, Signature, SignatureSize, VerifyingKey,
+};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use elliptic_curve::{
+    generic_array::{typenum::Unsigned, ArrayLength},
+    sec1::{self, EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytesSize, JwkParameters, PrimeCurve,
+};
+use minicbor::{
+    data::{Tag, Type},
+    decode, encode, Decoder, Encoder,
+};
+use signature::{digest::Digest, Verifier};
+
+#[cfg(feature = "signing")]
+use {
+    crate::{hazmat::SignPrimitive, SigningKey},
+    elliptic_curve::{ops::Invert, subtle::CtOption, zeroize::Zeroizing, Scalar},
+    signature::Signer,
+};
+
+/// `COSE_Key` label of the key type.
+const KEY_KTY: i64 = 1;
+
+/// `COSE_Key` label of the curve.
+const KEY_CRV: i64 = -1;
+
+/// `COSE_Key` label of the x-coordinate.
+const KEY_X: i64 = -2;
+
+/// `COSE_Key` label of the y-coordinate, or its sign bit.
+const KEY_Y: i64 = -3;
+
+/// `COSE_Key` label of the private key.
+const KEY_D: i64 = -4;
+
+/// `EC2` key type.
+const KTY_EC2: i64 = 2;
+
+/// Header label of the algorithm.
+const HEADER_ALG: i64 = 1;
+
+/// Header label of the critical headers.
+const HEADER_CRIT: i64 = 2;
+
+/// CBOR tag of a `COSE_Sign1` structure.
+const COSE_SIGN1_TAG: u64 = 18;
+
+/// Context string of the `Sig_structure` of a `COSE_Sign1` structure.
+const SIGNATURE1_CONTEXT: &str = "Signature1";
+
+/// Get the COSE Elliptic Curves registry value (`crv`) of the curve `C`.
+///
+/// Returns an error if the curve isn't registered for COSE.
+pub fn curve<C>() -> Result<i64>
+where
+    C: JwkParameters,
+{
+    match C::CRV {
+        "P-256" => Ok(1),
+        "P-384" => Ok(2),
+        "P-521" => Ok(3),
+        "secp256k1" => Ok(8),
+        _ => Err(Error::new()),
+    }
+}
+
+/// Get the COSE Algorithms registry value (`alg`) for signatures over the
+/// curve `C` using its [`DigestPrimitive`], as registered in
+/// [RFC 9053 Section 2.1][1] and [RFC 8812 Section 3.2][2].
+///
+/// Returns an error if no algorithm is registered for the combination of
+/// curve and digest.
+///
+/// [1]: https://www.rfc-editor.org/rfc/rfc9053#section-2.1
+/// [2]: https://www.rfc-editor.org/rfc/rfc8812#section-3.2
+pub fn algorithm<C>() -> Result<i64>
+where
+    C: DigestPrimitive + JwkParameters,
+{
+    match (curve::<C>()?, <C::Digest as Digest>::output_size()) {
+        // ES256
+        (1, 32) => Ok(-7),
+        // ES384
+        (2, 48) => Ok(-35),
+        // ES512
+        (3, 64) => Ok(-36),
+        // ES256K
+        (8, 32) => Ok(-47),
+        _ => Err(Error::new()),
+    }
+}
+
+impl<C> VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + JwkParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+{
+    /// Parse a [`VerifyingKey`] from a CBOR-encoded `EC2` `COSE_Key`.
+    ///
+    /// The y-coordinate may be given either in full or as its sign bit.
+    /// Parameters other than `kty`, `crv`, `x`, `y`, and `d` are ignored.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self> {
+        let (public_key, _) = decode_key::<C>(bytes).map_err(|_| Error::new())?;
+        Self::from_encoded_point(&public_key.ok_or_else(Error::new)?)
+    }
+
+    /// Serialize this [`VerifyingKey`] as a CBOR-encoded `EC2` `COSE_Key`.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>> {
+        encode_key::<C>(curve::<C>()?, &self.to_encoded_point(false), None)
+            .map_err(|_| Error::new())
+    }
+
+    /// Verify a CBOR-encoded `COSE_Sign1` structure with the given externally
+    /// supplied data, returning its payload.
+    ///
+    /// The structure may optionally be tagged. Its protected header must
+    /// specify the algorithm returned by [`algorithm`] for this curve, and
+    /// must not contain any critical headers. Detached payloads aren't
+    /// supported.
+    pub fn verify_cose_sign1(&self, cose_sign1: &[u8], external_aad: &[u8]) -> Result<Vec<u8>>
+    where
+        C: DigestPrimitive,
+        AffinePoint<C>: VerifyPrimitive<C>,
+        SignatureSize<C>: ArrayLength<u8>,
+    {
+        let sign1 = decode_sign1(cose_sign1).map_err(|_| Error::new())?;
+        if decode_protected_alg(sign1.protected).map_err(|_| Error::new())? != algorithm::<C>()? {
+            return Err(Error::new());
+        }
+
+        let signature = Signature::<C>::from_slice(sign1.signature)?;
+        let to_be_signed = encode_to_be_signed(sign1.protected, external_aad, sign1.payload)
+            .map_err(|_| Error::new())?;
+        self.verify(&to_be_signed, &signature)?;
+
+        Ok(sign1.payload.to_vec())
+    }
+}
+
+#[cfg(feature = "signing")]
+impl<C> SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + JwkParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Parse a [`SigningKey`] from a CBOR-encoded `EC2` `COSE_Key` containing
+    /// the private key `d`.
+    ///
+    /// If the public key is present as well, it must match the private key.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self> {
+        let (public_key, d) = decode_key::<C>(bytes).map_err(|_| Error::new())?;
+        let signing_key = Self::from_slice(d.ok_or_else(Error::new)?)?;
+
+        if let Some(public_key) = public_key {
+            let verifying_key = signing_key.verifying_key();
+            if verifying_key.to_encoded_point(public_key.is_compressed()) != public_key {
+                return Err(Error::new());
+            }
+        }
+
+        Ok(signing_key)
+    }
+
+    /// Serialize this [`SigningKey`] as a CBOR-encoded `EC2` `COSE_Key`,
+    /// including the private key `d`.
+    pub fn to_cose_key(&self) -> Result<Zeroizing<Vec<u8>>> {
+        let public_key = self.verifying_key().to_encoded_point(false);
+        let d = Zeroizing::new(self.to_bytes());
+
+        encode_key::<C>(curve::<C>()?, &public_key, Some(&d))
+            .map(Zeroizing::new)
+            .map_err(|_| Error::new())
+    }
+
+    /// Sign the given payload and externally supplied data, returning a
+    /// tagged, CBOR-encoded `COSE_Sign1` structure.
+    ///
+    /// The protected header only contains the algorithm returned by
+    /// [`algorithm`] for this curve, and the unprotected header is empty.
+    pub fn sign_cose_sign1(&self, payload: &[u8], external_aad: &[u8]) -> Result<Vec<u8>>
+    where
+        C: DigestPrimitive,
+    {
+        let protected = encode_protected(algorithm::<C>()?).map_err(|_| Error::new())?;
+        let to_be_signed =
+            encode_to_be_signed(&protected, external_aad, payload).map_err(|_| Error::new())?;
+        let signature: Signature<C> = self.try_sign(&to_be_signed)?;
+
+        encode_sign1(&protected, payload, &signature.to_bytes()).map_err(|_| Error::new())
+    }
+}
+
+/// Fields of a `COSE_Sign1` structure.
+struct Sign1<'a> {
+    /// Serialized protected header.
+    protected: &'a [u8],
+
+    /// Attached payload.
+    payload: &'a [u8],
+
+    /// Signature over the `Sig_structure`.
+    signature: &'a [u8],
+}
+
+/// Public key and private key `d` of a `COSE_Key`, if present.
+type DecodedKey<'a, C> = (Option<EncodedPoint<C>>, Option<&'a [u8]>);
+
+/// y-coordinate of an `EC2` `COSE_Key`.
+enum YCoordinate<'a> {
+    /// Full y-coordinate.
+    Value(&'a [u8]),
+
+    /// Sign bit of the y-coordinate, i.e. whether it is odd.
+    Sign(bool),
+}
+
+/// Decode a `COSE_Key`, returning its public key and private key `d`, if
+/// present.
+fn decode_key<C>(bytes: &[u8]) -> core::result::Result<DecodedKey<'_, C>, decode::Error>
+where
+    C: PrimeCurve + JwkParameters,
+    FieldBytesSize<C>: sec1::ModulusSize,
+{
+    let mut decoder = Decoder::new(bytes);
+    let (mut kty, mut crv, mut x, mut y, mut d) = (None, None, None, None, None);
+
+    let entries = decoder
+        .map()?
+        .ok_or_else(|| decode::Error::message("indefinite length map"))?;
+    for _ in 0..entries {
+        match decode_label(&mut decoder)? {
+            Some(KEY_KTY) => set_once(&mut kty, decoder.i64()?)?,
+            Some(KEY_CRV) => set_once(&mut crv, decoder.i64()?)?,
+            Some(KEY_X) => set_once(&mut x, decoder.bytes()?)?,
+            Some(KEY_Y) => match decoder.datatype()? {
+                Type::Bool => set_once(&mut y, YCoordinate::Sign(decoder.bool()?))?,
+                _ => set_once(&mut y, YCoordinate::Value(decoder.bytes()?))?,
+            },
+            Some(KEY_D) => set_once(&mut d, decoder.bytes()?)?,
+            _ => decoder.skip()?,
+        }
+    }
+
+    if decoder.position() != bytes.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    if kty != Some(KTY_EC2) {
+        return Err(decode::Error::message("unsupported key type"));
+    }
+
+    if crv.is_none() || crv != curve::<C>().ok() {
+        return Err(decode::Error::message("unsupported curve"));
+    }
+
+    let public_key = match (x, y) {
+        (Some(x), Some(y)) if x.len() == FieldBytesSize::<C>::USIZE => {
+            let mut point = Vec::with_capacity(1 + 2 * x.len());
+            match y {
+                YCoordinate::Value(y) => {
+                    point.push(sec1::Tag::Uncompressed.into());
+                    point.extend_from_slice(x);
+                    point.extend_from_slice(y);
+                }
+                YCoordinate::Sign(is_y_odd) => {
+                    point.push(if is_y_odd {
+                        sec1::Tag::CompressedOddY.into()
+                    } else {
+                        sec1::Tag::CompressedEvenY.into()
+                    });
+                    point.extend_from_slice(x);
+                }
+            }
+
+            let point = EncodedPoint::<C>::from_bytes(point)
+                .map_err(|_| decode::Error::message("invalid public key"))?;
+            Some(point)
+        }
+        (None, None) => None,
+        _ => return Err(decode::Error::message("invalid public key")),
+    };
+
+    Ok((public_key, d))
+}
+
+/// Encode a `COSE_Key` for the given curve, public key and optional private
+/// key `d`.
+fn encode_key<C>(
+    crv: i64,
+    public_key: &EncodedPoint<C>,
+    d: Option<&[u8]>,
+) -> core::result::Result<Vec<u8>, encode::Error<Infallible>>
+where
+    C: PrimeCurve,
+    FieldBytesSize<C>: sec1::ModulusSize,
+{
+    let (x, y) = match (public_key.x(), public_key.y()) {
+        (Some(x), Some(y)) => (x, y),
+        _ => return Err(encode::Error::message("invalid public key")),
+    };
+
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .map(if d.is_some() { 5 } else { 4 })?
+        .i64(KEY_KTY)?
+        .i64(KTY_EC2)?
+        .i64(KEY_CRV)?
+        .i64(crv)?
+        .i64(KEY_X)?
+        .bytes(x)?
+        .i64(KEY_Y)?
+        .bytes(y)?;
+
+    if let Some(d) = d {
+        encoder.i64(KEY_D)?.bytes(d)?;
+    }
+
+    Ok(encoder.into_writer())
+}
+
+/// Decode a `COSE_Sign1` structure, which may optionally be tagged.
+fn decode_sign1(bytes: &[u8]) -> core::result::Result<Sign1<'_>, decode::Error> {
+    let mut decoder = Decoder::new(bytes);
+
+    if decoder.datatype()? == Type::Tag && decoder.tag()? != Tag::Unassigned(COSE_SIGN1_TAG) {
+        return Err(decode::Error::message("unexpected tag"));
+    }
+
+    if decoder.array()? != Some(4) {
+        return Err(decode::Error::message("expected array of 4 elements"));
+    }
+
+    let protected = decoder.bytes()?;
+    if decoder.datatype()? != Type::Map {
+        return Err(decode::Error::message("expected unprotected header map"));
+    }
+    decoder.skip()?;
+    let payload = decoder.bytes()?;
+    let signature = decoder.bytes()?;
+
+    if decoder.position() != bytes.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    Ok(Sign1 {
+        protected,
+        payload,
+        signature,
+    })
+}
+
+/// Encode a tagged `COSE_Sign1` structure with an empty unprotected header.
+#[cfg(feature = "signing")]
+fn encode_sign1(
+    protected: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> core::result::Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .tag(Tag::Unassigned(COSE_SIGN1_TAG))?
+        .array(4)?
+        .bytes(protected)?
+        .map(0)?
+        .bytes(payload)?
+        .bytes(signature)?;
+    Ok(encoder.into_writer())
+}
+
+/// Decode the algorithm from a serialized protected header, rejecting any
+/// critical headers.
+fn decode_protected_alg(protected: &[u8]) -> core::result::Result<i64, decode::Error> {
+    let mut decoder = Decoder::new(protected);
+    let mut alg = None;
+
+    let entries = decoder
+        .map()?
+        .ok_or_else(|| decode::Error::message("indefinite length map"))?;
+    for _ in 0..entries {
+        match decode_label(&mut decoder)? {
+            Some(HEADER_ALG) => set_once(&mut alg, decoder.i64()?)?,
+            Some(HEADER_CRIT) => return Err(decode::Error::message("unsupported critical header")),
+            _ => decoder.skip()?,
+        }
+    }
+
+    if decoder.position() != protected.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    alg.ok_or_else(|| decode::Error::message("missing algorithm"))
+}
+
+/// Encode a protected header containing only the given algorithm.
+#[cfg(feature = "signing")]
+fn encode_protected(alg: i64) -> core::result::Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder.map(1)?.i64(HEADER_ALG)?.i64(alg)?;
+    Ok(encoder.into_writer())
+}
+
+/// Encode the `Sig_structure` of a `COSE_Sign1` structure, i.e. the data
+/// which is actually signed.
+fn encode_to_be_signed(
+    protected: &[u8],
+    external_aad: &[u8],
+    payload: &[u8],
+) -> core::result::Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .array(4)?
+        .str(SIGNATURE1_CONTEXT)?
+        .bytes(protected)?
+        .bytes(external_aad)?
+        .bytes(payload)?;
+    Ok(encoder.into_writer())
+}
+
+/// Decode a map label, returning `None` (after skipping it) if it isn't an
+/// integer.
+fn decode_label(decoder: &mut Decoder<'_>) -> core::result::Result<Option<i64>, decode::Error> {
+    match decoder.datatype()? {
+        Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::Int => decoder.i64().map(Some),
+        _ => decoder.skip().map(|_| None),
+    }
+}
+
+/// Set a map value, rejecting duplicate labels.
+fn set_once<T>(slot: &mut Option<T>, value: T) -> core::result::Result<(), decode::Error> {
+    match slot.replace(value) {
+        Some(_) => Err(decode::Error::message("duplicate label")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        algorithm, curve, decode_key, decode_protected_alg, decode_sign1, encode_to_be_signed,
+    };
+    use alloc::vec::Vec;
+    use elliptic_curve::dev::MockCurve;
+    use hex_literal::hex;
+
+    type VerifyingKey = crate::VerifyingKey<MockCurve>;
+
+    /// P-256 public key `11` from RFC 9052 Appendix C.7.1.
+    const COSE_KEY: [u8; 75] = hex!(
+        "a401022001215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff"
+        "22582020138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e"
+    );
+
+    /// The same key with a `kid` and a text label, as well as a compressed
+    /// y-coordinate.
+    const COSE_KEY_COMPRESSED: [u8; 51] = hex!(
+        "a6010220010242313163666f6f00215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f74522825"
+        "5a219a86d6a09eff22f4"
+    );
+
+    /// `COSE_Sign1` example from RFC 9052 Appendix C.2.1.
+    const COSE_SIGN1: [u8; 98] = hex!(
+        "d28443a10126a10442313154546869732069732074686520636f6e74656e742e5840"
+        "8eb33e4ca31d1c465ab05aac34cc6b23d58fef5c083106c4d25a91aef0b0117e"
+        "2af9a291aa32e14ab834dc56ed2a223444547e01f11d3b0916e5a4c345cacb36"
+    );
+
+    #[test]
+    fn cose_key_roundtrip() {
+        let verifying_key = VerifyingKey::from_cose_key(&COSE_KEY).unwrap();
+        assert_eq!(verifying_key.to_cose_key().unwrap(), COSE_KEY);
+    }
+
+    #[test]
+    fn cose_key_compressed() {
+        let (public_key, d) = decode_key::<MockCurve>(&COSE_KEY_COMPRESSED).unwrap();
+        let public_key = public_key.unwrap();
+        assert!(public_key.is_compressed());
+        assert_eq!(public_key.as_bytes()[0], 0x02);
+        assert_eq!(public_key.x().unwrap().as_slice(), &COSE_KEY[8..40]);
+        assert_eq!(d, None);
+    }
+
+    #[test]
+    fn cose_key_invalid() {
+        // OKP key type
+        let mut key = COSE_KEY;
+        key[2] = 0x01;
+        assert!(VerifyingKey::from_cose_key(&key).is_err());
+
+        // P-384 curve
+        let mut key = COSE_KEY;
+        key[4] = 0x02;
+        assert!(VerifyingKey::from_cose_key(&key).is_err());
+
+        // Duplicate `kty` instead of `crv`
+        let mut key = COSE_KEY;
+        key[3] = 0x01;
+        assert!(VerifyingKey::from_cose_key(&key).is_err());
+
+        // Trailing data
+        let mut key = COSE_KEY.to_vec();
+        key.push(0);
+        assert!(VerifyingKey::from_cose_key(&key).is_err());
+    }
+
+    #[test]
+    fn decode_cose_sign1() {
+        let sign1 = decode_sign1(&COSE_SIGN1).unwrap();
+        assert_eq!(sign1.protected, hex!("a10126"));
+        assert_eq!(sign1.payload, b"This is the content.");
+        assert_eq!(sign1.signature, &COSE_SIGN1[34..]);
+        assert_eq!(decode_protected_alg(sign1.protected).unwrap(), -7);
+
+        // Untagged
+        assert!(decode_sign1(&COSE_SIGN1[1..]).is_ok());
+
+        // Detached payload
+        let detached = hex!("8443a10126a0f640");
+        assert!(decode_sign1(&detached).is_err());
+    }
+
+    #[test]
+    fn to_be_signed() {
+        let to_be_signed =
+            encode_to_be_signed(&hex!("a10126"), b"", b"This is the content.").unwrap();
+        assert_eq!(
+            to_be_signed,
+            hex!("846a5369676e61747572653143a101264054546869732069732074686520636f6e74656e742e")
+        );
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn protected_header() {
+        assert_eq!(super::encode_protected(-7).unwrap(), hex!("a10126"));
+    }
+
+    #[test]
+    fn reject_critical_headers() {
+        // {1: -7, 2: [4]}
+        assert!(decode_protected_alg(&hex!("a20126028104")).is_err());
+    }
+
+    #[test]
+    fn algorithms() {
+        assert_eq!(curve::<MockCurve>().unwrap(), 1);
+        assert_eq!(algorithm::<MockCurve>().unwrap(), -7);
+    }
+
+    /// [`COSE_SIGN1`] with its protected header replaced.
+    fn with_protected(protected: &[u8]) -> Vec<u8> {
+        let mut cose_sign1 = hex!("d284").to_vec();
+        cose_sign1.push(0x40 + u8::try_from(protected.len()).unwrap());
+        cose_sign1.extend_from_slice(protected);
+        cose_sign1.extend_from_slice(&COSE_SIGN1[6..]);
+        cose_sign1
+    }
+
+    #[test]
+    fn verify_cose_sign1_rejects_header() {
+        let verifying_key = VerifyingKey::from_cose_key(&COSE_KEY).unwrap();
+
+        for protected in [
+            // {1: -8}, i.e. EdDSA
+            &hex!("a10127")[..],
+            // {1: -35}, i.e. ES384
+            &hex!("a1013822"),
+            // {1: "ES256"}
+            &hex!("a101654553323536"),
+            // {}
+            &hex!("a0"),
+            // {1: -7, 2: [4]}
+            &hex!("a20126028104"),
+            // {1: -7, 1: -7}
+            &hex!("a201260126"),
+            // {1: -7} followed by trailing data
+            &hex!("a1012600"),
+        ] {
+            let cose_sign1 = with_protected(protected);
+            assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+        }
+    }
+
+    #[test]
+    fn verify_cose_sign1_rejects_malformed() {
+        let verifying_key = VerifyingKey::from_cose_key(&COSE_KEY).unwrap();
+
+        // COSE_Mac0 tag
+        let mut cose_sign1 = COSE_SIGN1;
+        cose_sign1[0] = 0xd1;
+        assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+
+        // Array of 3 elements
+        let mut cose_sign1 = COSE_SIGN1;
+        cose_sign1[1] = 0x83;
+        assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+
+        // Truncated signature
+        let mut cose_sign1 = COSE_SIGN1.to_vec();
+        cose_sign1[33] = 0x3f;
+        cose_sign1.pop();
+        assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+
+        // Trailing data
+        let mut cose_sign1 = COSE_SIGN1.to_vec();
+        cose_sign1.push(0);
+        assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+    }
+}
@@ -12,7 +12,9 @@
 
 use crate::{Error, Result};
 use core::cmp;
-use elliptic_curve::{generic_array::typenum::Unsigned, FieldBytes, PrimeCurve};
+use elliptic_curve::{
+    generic_array::typenum::Unsigned, FieldBytes, FieldBytesEncoding, PrimeCurve,
+};
 
 #[cfg(feature = "arithmetic")]
 use {
@@ -38,7 +40,7 @@ use {
 };
 
 #[cfg(feature = "rfc6979")]
-use elliptic_curve::{FieldBytesEncoding, ScalarPrimitive};
+use elliptic_curve::ScalarPrimitive;
 
 #[cfg(any(feature = "arithmetic", feature = "digest"))]
 use crate::{elliptic_curve::generic_array::ArrayLength, Signature};
@@ -89,6 +91,9 @@ where
     /// - `z`: message digest to be signed.
     /// - `ad`: optional additional data, e.g. added entropy from an RNG
     ///
+    /// The output size of the `HMAC_DRBG` digest `D` need not match the size
+    /// of the curve's scalar field.
+    ///
     /// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
     #[cfg(feature = "rfc6979")]
     fn try_sign_prehashed_rfc6979<D>(
@@ -98,18 +103,51 @@ where
     ) -> Result<(Signature<C>, Option<RecoveryId>)>
     where
         Self: From<ScalarPrimitive<C>> + Invert<Output = CtOption<Self>>,
-        D: Digest + BlockSizeUser + FixedOutput<OutputSize = FieldBytesSize<C>> + FixedOutputReset,
+        D: Digest + BlockSizeUser + FixedOutputReset,
     {
-        let k = Scalar::<C>::from_repr(rfc6979::generate_k::<D, _>(
+        // RFC6979 § 3.2 step d requires `bits2octets(h1)`, i.e. `z` reduced
+        // modulo the curve's order
+        let k = Scalar::<C>::from_repr(rfc6979::generate_k_mixed::<D, _>(
             &self.to_repr(),
             &C::ORDER.encode_field_bytes(),
-            z,
+            &Self::reduce_bytes(z).to_repr(),
             ad,
         ))
         .unwrap();
 
         self.try_sign_prehashed::<Self>(k, z)
     }
+
+    /// Try to sign the given message digest using the hedged construction
+    /// described in [draft-irtf-cfrg-det-sigs-with-noise] for computing ECDSA
+    /// ephemeral scalar `k`, which combines [RFC6979] with fresh randomness.
+    ///
+    /// Accepts the following parameters:
+    /// - `z`: message digest to be signed.
+    /// - `noise`: random string `Z`. MUST BE FRESH CSRNG OUTPUT FOR EACH SIGNATURE!!!
+    ///
+    /// [draft-irtf-cfrg-det-sigs-with-noise]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-det-sigs-with-noise
+    /// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
+    #[cfg(feature = "rfc6979")]
+    fn try_sign_prehashed_hedged<D>(
+        &self,
+        z: &FieldBytes<C>,
+        noise: &[u8],
+    ) -> Result<(Signature<C>, Option<RecoveryId>)>
+    where
+        Self: From<ScalarPrimitive<C>> + Invert<Output = CtOption<Self>>,
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        let k = Scalar::<C>::from_repr(rfc6979::generate_k_hedged::<D, _>(
+            &self.to_repr(),
+            &C::ORDER.encode_field_bytes(),
+            &Self::reduce_bytes(z).to_repr(),
+            noise,
+        ))
+        .unwrap();
+
+        self.try_sign_prehashed::<Self>(k, z)
+    }
 }
 
 /// Verify the given prehashed message using ECDSA.
@@ -180,6 +218,10 @@ where
 /// larger than the size of the curve's scalar field into a serialized
 /// (unreduced) field element.
 ///
+/// When the digest is larger than the field size, only its leftmost `qlen`
+/// bits are kept, where `qlen` is the bit length of the curve's order. This
+/// matters for curves like P-521 whose order is not a multiple of 8 bits.
+///
 /// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
 /// [SEC1]: https://www.secg.org/sec1-v2.pdf
 pub fn bits2field<C: PrimeCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
@@ -202,9 +244,40 @@ pub fn bits2field<C: PrimeCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
         }
     }
 
+    // Discard any remaining rightmost bits in excess of the order's bit length
+    let qlen = order_bits::<C>();
+    let shift = (cmp::min(bits.len(), C::FieldBytesSize::USIZE) * 8).saturating_sub(qlen);
+
+    let (byte_shift, bit_shift) = (shift / 8, shift % 8);
+
+    if byte_shift > 0 {
+        let len = field_bytes.len();
+        field_bytes.copy_within(..(len - byte_shift), byte_shift);
+        field_bytes[..byte_shift].fill(0);
+    }
+
+    if bit_shift > 0 {
+        let mut carry = 0;
+        for byte in field_bytes.iter_mut() {
+            let next_carry = *byte << (8 - bit_shift);
+            *byte = (*byte >> bit_shift) | carry;
+            carry = next_carry;
+        }
+    }
+
     Ok(field_bytes)
 }
 
+/// Compute the bit length of the curve's order, i.e. `qlen`.
+fn order_bits<C: PrimeCurve>() -> usize {
+    let order = C::ORDER.encode_field_bytes();
+
+    match order.iter().position(|&byte| byte != 0) {
+        Some(i) => (order.len() - i) * 8 - order[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
 /// Sign a prehashed message digest using the provided secret scalar and
 /// ephemeral scalar, returning an ECDSA signature.
 ///
@@ -298,6 +371,16 @@ mod tests {
     use elliptic_curve::dev::MockCurve;
     use hex_literal::hex;
 
+    #[cfg(feature = "digest")]
+    impl super::DigestPrimitive for MockCurve {
+        type Digest = sha2::Sha256;
+    }
+
+    /// `MockCurve` has no point arithmetic, so tests can only exercise the
+    /// checks done before a signature is actually verified.
+    #[cfg(feature = "arithmetic")]
+    impl super::VerifyPrimitive<MockCurve> for elliptic_curve::dev::AffinePoint {}
+
     #[test]
     fn bits2field_too_small() {
         assert!(bits2field::<MockCurve>(b"").is_err());
@@ -0,0 +1,295 @@
+//! JSON Object Signing and Encryption (JOSE) support.
+//!
+//! This module provides:
+//!
+//! - [JSON Web Key (JWK)][RFC7517] import and export for [`VerifyingKey`] and
+//!   [`SigningKey`], based on the [`JwkEcKey`] type from the `elliptic-curve`
+//!   crate
+//! - [JWK thumbprints][RFC7638] of a [`VerifyingKey`]
+//! - [JSON Web Signatures (JWS)][RFC7515] in compact serialization, using the
+//!   `alg` returned by [`algorithm`]
+//!
+//! [RFC7515]: https://www.rfc-editor.org/rfc/rfc7515
+//! [RFC7517]: https://www.rfc-editor.org/rfc/rfc7517
+//! [RFC7638]: https://www.rfc-editor.org/rfc/rfc7638
+
+pub use elliptic_curve::{JwkEcKey, JwkParameters};
+
+use crate::{
+    hazmat::{DigestPrimitive, VerifyPrimitive},
+    Error, Result, Signature, SignatureSize, VerifyingKey,
+};
+use alloc::{format, string::String, vec::Vec};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use elliptic_curve::{
+    generic_array::{typenum::Unsigned, ArrayLength},
+    sec1::{self, FromEncodedPoint, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytesSize, PrimeCurve, PublicKey,
+};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use signature::Verifier;
+
+#[cfg(feature = "signing")]
+use {
+    crate::{hazmat::SignPrimitive, SigningKey},
+    elliptic_curve::{ops::Invert, subtle::CtOption, zeroize::Zeroizing, Scalar, SecretKey},
+    signature::Signer,
+};
+
+/// Get the JWS `alg` value for signatures over the curve `C` using its
+/// [`DigestPrimitive`], as registered in [RFC 7518 Section 3.1][1] and
+/// [RFC 8812 Section 3.2][2].
+///
+/// Returns an error if no `alg` is registered for the combination of curve
+/// and digest.
+///
+/// [1]: https://www.rfc-editor.org/rfc/rfc7518#section-3.1
+/// [2]: https://www.rfc-editor.org/rfc/rfc8812#section-3.2
+pub fn algorithm<C>() -> Result<&'static str>
+where
+    C: DigestPrimitive + JwkParameters,
+{
+    jws_algorithm(C::CRV, <C::Digest as Digest>::output_size())
+}
+
+/// Get the JWS `alg` value for the given JWK `crv` and digest output size.
+fn jws_algorithm(crv: &str, digest_size: usize) -> Result<&'static str> {
+    match (crv, digest_size) {
+        ("P-256", 32) => Ok("ES256"),
+        ("P-384", 48) => Ok("ES384"),
+        ("P-521", 64) => Ok("ES512"),
+        ("secp256k1", 32) => Ok("ES256K"),
+        _ => Err(Error::new()),
+    }
+}
+
+impl<C> VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + JwkParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+{
+    /// Parse a [`VerifyingKey`] from a [`JwkEcKey`].
+    pub fn from_jwk(jwk: &JwkEcKey) -> Result<Self> {
+        PublicKey::from_jwk(jwk)
+            .map(|inner| Self { inner })
+            .map_err(|_| Error::new())
+    }
+
+    /// Parse a [`VerifyingKey`] from a string containing a JSON Web Key.
+    pub fn from_jwk_str(jwk: &str) -> Result<Self> {
+        PublicKey::from_jwk_str(jwk)
+            .map(|inner| Self { inner })
+            .map_err(|_| Error::new())
+    }
+
+    /// Serialize this [`VerifyingKey`] as a [`JwkEcKey`].
+    pub fn to_jwk(&self) -> JwkEcKey {
+        self.inner.to_jwk()
+    }
+
+    /// Serialize this [`VerifyingKey`] as a string containing a JSON Web Key.
+    pub fn to_jwk_string(&self) -> String {
+        self.inner.to_jwk_string()
+    }
+
+    /// Compute the [RFC 7638] JWK thumbprint of this key, i.e. the SHA-256
+    /// hash of its canonical JWK representation, encoded as unpadded
+    /// Base64url as commonly used for the `kid` parameter.
+    ///
+    /// [RFC 7638]: https://www.rfc-editor.org/rfc/rfc7638
+    pub fn jwk_thumbprint(&self) -> String {
+        let point = self.to_encoded_point(false);
+        let (x, y) = point.as_bytes()[1..].split_at(FieldBytesSize::<C>::USIZE);
+
+        // Members in lexicographic order, without whitespace
+        let canonical_jwk = format!(
+            r#"{{"crv":"{}","kty":"EC","x":"{}","y":"{}"}}"#,
+            C::CRV,
+            Base64UrlUnpadded::encode_string(x),
+            Base64UrlUnpadded::encode_string(y),
+        );
+
+        Base64UrlUnpadded::encode_string(&Sha256::digest(canonical_jwk))
+    }
+
+    /// Verify a JWS in compact serialization, returning its decoded payload.
+    ///
+    /// The JOSE header must specify the `alg` returned by [`algorithm`] for
+    /// this curve, and must not contain any `crit` extensions.
+    pub fn verify_jws(&self, jws: &str) -> Result<Vec<u8>>
+    where
+        C: DigestPrimitive,
+        AffinePoint<C>: VerifyPrimitive<C>,
+        SignatureSize<C>: ArrayLength<u8>,
+    {
+        let (signing_input, signature) = jws.rsplit_once('.').ok_or_else(Error::new)?;
+        let (header, payload) = signing_input.split_once('.').ok_or_else(Error::new)?;
+
+        let header = Base64UrlUnpadded::decode_vec(header).map_err(|_| Error::new())?;
+        let header: Map<String, Value> =
+            serde_json::from_slice(&header).map_err(|_| Error::new())?;
+
+        if header.get("alg").and_then(Value::as_str) != Some(algorithm::<C>()?)
+            || header.contains_key("crit")
+        {
+            return Err(Error::new());
+        }
+
+        let signature = Base64UrlUnpadded::decode_vec(signature).map_err(|_| Error::new())?;
+        let signature = Signature::<C>::from_slice(&signature)?;
+        self.verify(signing_input.as_bytes(), &signature)?;
+
+        Base64UrlUnpadded::decode_vec(payload).map_err(|_| Error::new())
+    }
+}
+
+#[cfg(feature = "signing")]
+impl<C> SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + JwkParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Parse a [`SigningKey`] from a [`JwkEcKey`] containing the private
+    /// key parameter `d`.
+    pub fn from_jwk(jwk: &JwkEcKey) -> Result<Self> {
+        SecretKey::from_jwk(jwk)
+            .map(Into::into)
+            .map_err(|_| Error::new())
+    }
+
+    /// Parse a [`SigningKey`] from a string containing a JSON Web Key with
+    /// the private key parameter `d`.
+    pub fn from_jwk_str(jwk: &str) -> Result<Self> {
+        SecretKey::from_jwk_str(jwk)
+            .map(Into::into)
+            .map_err(|_| Error::new())
+    }
+
+    /// Serialize this [`SigningKey`] as a [`JwkEcKey`], including the private
+    /// key parameter `d`.
+    pub fn to_jwk(&self) -> JwkEcKey {
+        SecretKey::from(self).to_jwk()
+    }
+
+    /// Serialize this [`SigningKey`] as a string containing a JSON Web Key,
+    /// including the private key parameter `d`.
+    pub fn to_jwk_string(&self) -> Zeroizing<String> {
+        SecretKey::from(self).to_jwk_string()
+    }
+
+    /// Sign the given payload, returning a JWS in compact serialization.
+    ///
+    /// The JOSE header only contains the `alg` returned by [`algorithm`] for
+    /// this curve.
+    pub fn sign_jws(&self, payload: &[u8]) -> Result<String>
+    where
+        C: DigestPrimitive,
+    {
+        let header = format!(r#"{{"alg":"{}"}}"#, algorithm::<C>()?);
+        let mut jws = format!(
+            "{}.{}",
+            Base64UrlUnpadded::encode_string(header.as_bytes()),
+            Base64UrlUnpadded::encode_string(payload)
+        );
+
+        let signature: Signature<C> = self.try_sign(jws.as_bytes())?;
+        jws.push('.');
+        jws.push_str(&Base64UrlUnpadded::encode_string(&signature.to_bytes()));
+        Ok(jws)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{algorithm, jws_algorithm};
+    use alloc::{format, string::String};
+    use base64ct::{Base64UrlUnpadded, Encoding};
+    use elliptic_curve::dev::MockCurve;
+
+    type VerifyingKey = crate::VerifyingKey<MockCurve>;
+
+    /// Example public key from RFC 7517 Appendix A.1.
+    const JWK: &str = r#"{"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#;
+
+    #[test]
+    fn jwk_roundtrip() {
+        let verifying_key = VerifyingKey::from_jwk_str(JWK).unwrap();
+        assert_eq!(verifying_key.to_jwk_string(), JWK);
+        assert_eq!(
+            VerifyingKey::from_jwk(&verifying_key.to_jwk()).unwrap(),
+            verifying_key
+        );
+    }
+
+    #[test]
+    fn jwk_thumbprint() {
+        let verifying_key = VerifyingKey::from_jwk_str(JWK).unwrap();
+        assert_eq!(
+            verifying_key.jwk_thumbprint(),
+            "cn-I_WNMClehiVp51i_0VpOENW1upEerA8sEam5hn-s"
+        );
+    }
+
+    #[test]
+    fn algorithms() {
+        assert_eq!(algorithm::<MockCurve>().unwrap(), "ES256");
+
+        assert_eq!(jws_algorithm("P-256", 32).unwrap(), "ES256");
+        assert_eq!(jws_algorithm("P-384", 48).unwrap(), "ES384");
+        assert_eq!(jws_algorithm("P-521", 64).unwrap(), "ES512");
+        assert_eq!(jws_algorithm("secp256k1", 32).unwrap(), "ES256K");
+
+        // Unregistered combinations of curve and digest
+        assert!(jws_algorithm("P-256", 48).is_err());
+        assert!(jws_algorithm("P-521", 66).is_err());
+        assert!(jws_algorithm("brainpoolP256r1", 32).is_err());
+    }
+
+    #[test]
+    fn verify_jws_rejects_header() {
+        let verifying_key = VerifyingKey::from_jwk_str(JWK).unwrap();
+        let signature = Base64UrlUnpadded::encode_string(&[1; 64]);
+
+        for header in [
+            r#"{"alg":"ES384"}"#,
+            r#"{"alg":"none"}"#,
+            r#"{"alg":"es256"}"#,
+            r#"{"alg":"ES256","crit":["exp"],"exp":0}"#,
+            r#"{"typ":"JWT"}"#,
+            r#"{"alg":256}"#,
+            r#"["ES256"]"#,
+            "ES256",
+        ] {
+            let jws = format!(
+                "{}.cGF5bG9hZA.{}",
+                Base64UrlUnpadded::encode_string(header.as_bytes()),
+                signature
+            );
+            assert!(verifying_key.verify_jws(&jws).is_err(), "{header}");
+        }
+    }
+
+    #[test]
+    fn verify_jws_rejects_malformed() {
+        let verifying_key = VerifyingKey::from_jwk_str(JWK).unwrap();
+        let signature = Base64UrlUnpadded::encode_string(&[1; 64]);
+
+        for jws in [
+            String::new(),
+            "eyJhbGciOiJFUzI1NiJ9".into(),
+            format!("eyJhbGciOiJFUzI1NiJ9.{signature}"),
+            format!("eyJhbGciOiJFUzI1NiJ9=.cGF5bG9hZA.{signature}"),
+            format!("eyJhbGciOiJFUzI1NiJ9.cGF5bG9hZA.{signature}="),
+            // Signature of the wrong length
+            "eyJhbGciOiJFUzI1NiJ9.cGF5bG9hZA.AQEB".into(),
+            "eyJhbGciOiJFUzI1NiJ9.cGF5bG9hZA.".into(),
+        ] {
+            assert!(verifying_key.verify_jws(&jws).is_err(), "{jws}");
+        }
+    }
+}
@@ -31,6 +31,18 @@
 //!
 //! Please see type-specific documentation for more information.
 //!
+//! ## JOSE support
+//!
+//! When the `jose` feature of this crate is enabled, the `jose` module
+//! provides JSON Web Key import/export and thumbprints for the [`SigningKey`]
+//! and [`VerifyingKey`] types, along with compact JSON Web Signatures.
+//!
+//! ## COSE support
+//!
+//! When the `cose` feature of this crate is enabled, the `cose` module
+//! provides `COSE_Key` import/export for the [`SigningKey`] and
+//! [`VerifyingKey`] types, along with `COSE_Sign1` signatures.
+//!
 //! ## Interop
 //!
 //! Any crates which provide an implementation of ECDSA for a particular
@@ -60,18 +72,27 @@ extern crate alloc;
 mod normalized;
 mod recovery;
 
+#[cfg(feature = "cose")]
+pub mod cose;
 #[cfg(feature = "der")]
 pub mod der;
 #[cfg(feature = "dev")]
 pub mod dev;
 #[cfg(feature = "hazmat")]
 pub mod hazmat;
+#[cfg(feature = "jose")]
+pub mod jose;
 #[cfg(feature = "signing")]
 mod signing;
 #[cfg(feature = "verifying")]
 mod verifying;
 
-pub use crate::{normalized::NormalizedSignature, recovery::RecoveryId};
+pub use crate::{
+    normalized::NormalizedSignature,
+    recovery::{
+        RecoverableSignature, RecoverableSignatureBytes, RecoverableSignatureSize, RecoveryId,
+    },
+};
 
 // Re-export the `elliptic-curve` crate (and select types)
 pub use elliptic_curve::{self, sec1::EncodedPoint, PrimeCurve};
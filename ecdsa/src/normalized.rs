@@ -3,9 +3,336 @@
 use crate::Signature;
 use elliptic_curve::PrimeCurve;
 
+#[cfg(feature = "arithmetic")]
+use {
+    crate::{Error, Result, SignatureBytes, SignatureEncoding, SignatureSize},
+    core::fmt,
+    elliptic_curve::{
+        generic_array::ArrayLength, scalar::IsHigh, CurveArithmetic, FieldBytes, NonZeroScalar,
+    },
+};
+
+#[cfg(all(feature = "arithmetic", feature = "alloc"))]
+use alloc::vec::Vec;
+
+#[cfg(all(feature = "arithmetic", feature = "der"))]
+use {crate::der, core::ops::Add, elliptic_curve::FieldBytesSize};
+
+#[cfg(all(feature = "arithmetic", feature = "serde"))]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
 /// ECDSA signature with low-S normalization applied.
+///
+/// The `s` component of this signature is guaranteed to be in the lower half
+/// of the scalar field, i.e. `s <= n / 2`, as described in
+/// [BIP 0062: Dealing with Malleability][1].
+///
+/// Parsing a signature whose `s` component is "high" (via any of the
+/// constructors or the [`TryFrom`] impls) returns an error, which makes this
+/// type suitable for applications which need to reject malleable signatures.
+///
+/// To convert an arbitrary [`Signature`] into its normalized form, use
+/// [`Signature::normalize_s`] or [`NormalizedSignature::from_signature`].
+///
+/// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
 #[derive(Clone, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct NormalizedSignature<C: PrimeCurve> {
     inner: Signature<C>,
 }
+
+#[cfg(feature = "arithmetic")]
+impl<C> NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a [`NormalizedSignature`] from an arbitrary [`Signature`],
+    /// normalizing `s` into its low form if necessary.
+    pub fn from_signature(signature: Signature<C>) -> Self {
+        let inner = signature.normalize_s().unwrap_or(signature);
+        Self { inner }
+    }
+
+    /// Parse a signature from fixed-width bytes, i.e. 2 * the size of
+    /// [`FieldBytes`] for a particular curve.
+    ///
+    /// # Returns
+    /// - `Ok(signature)` if the `r` and `s` components are both in the valid
+    ///   range `1..n` and `s` is low.
+    /// - `Err(err)` if the `r` and/or `s` component is out-of-range, or if
+    ///   `s` is high.
+    pub fn from_bytes(bytes: &SignatureBytes<C>) -> Result<Self> {
+        Signature::from_bytes(bytes).and_then(Self::try_from)
+    }
+
+    /// Parse a signature from a byte slice.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        Signature::from_slice(slice).and_then(Self::try_from)
+    }
+
+    /// Parse a signature from ASN.1 DER.
+    #[cfg(feature = "der")]
+    pub fn from_der(bytes: &[u8]) -> Result<Self>
+    where
+        der::MaxSize<C>: ArrayLength<u8>,
+        <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArrayLength<u8>,
+    {
+        Signature::from_der(bytes).and_then(Self::try_from)
+    }
+
+    /// Create a [`NormalizedSignature`] from the serialized `r` and `s` scalar
+    /// values which comprise the signature.
+    ///
+    /// Returns an error if `s` is high.
+    pub fn from_scalars(r: impl Into<FieldBytes<C>>, s: impl Into<FieldBytes<C>>) -> Result<Self> {
+        Signature::from_scalars(r, s).and_then(Self::try_from)
+    }
+
+    /// Borrow the inner [`Signature`].
+    pub fn signature(&self) -> &Signature<C> {
+        &self.inner
+    }
+
+    /// Get the `r` component of this signature
+    pub fn r(&self) -> NonZeroScalar<C> {
+        self.inner.r()
+    }
+
+    /// Get the `s` component of this signature
+    pub fn s(&self) -> NonZeroScalar<C> {
+        self.inner.s()
+    }
+
+    /// Split the signature into its `r` and `s` components, represented as bytes.
+    pub fn split_bytes(&self) -> (FieldBytes<C>, FieldBytes<C>) {
+        self.inner.split_bytes()
+    }
+
+    /// Serialize this signature as bytes.
+    pub fn to_bytes(&self) -> SignatureBytes<C> {
+        self.inner.to_bytes()
+    }
+
+    /// Serialize this signature as ASN.1 DER.
+    #[cfg(feature = "der")]
+    pub fn to_der(&self) -> der::Signature<C>
+    where
+        der::MaxSize<C>: ArrayLength<u8>,
+        <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArrayLength<u8>,
+    {
+        self.inner.to_der()
+    }
+
+    /// Convert this signature into a byte vector.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.inner.to_vec()
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> Copy for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+    <SignatureSize<C> as ArrayLength<u8>>::ArrayType: Copy,
+{
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<NormalizedSignature<C>> for Signature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: NormalizedSignature<C>) -> Signature<C> {
+        signature.inner
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<NormalizedSignature<C>> for SignatureBytes<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: NormalizedSignature<C>) -> SignatureBytes<C> {
+        signature.to_bytes()
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> SignatureEncoding for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Repr = SignatureBytes<C>;
+}
+
+/// Rejects signatures whose `s` component is high.
+#[cfg(feature = "arithmetic")]
+impl<C> TryFrom<Signature<C>> for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(signature: Signature<C>) -> Result<Self> {
+        if signature.s().is_high().into() {
+            return Err(Error::new());
+        }
+
+        Ok(Self { inner: signature })
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> TryFrom<&[u8]> for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self> {
+        Self::from_slice(slice)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> fmt::Debug for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ecdsa::NormalizedSignature<{:?}>(", C::default())?;
+
+        for byte in self.to_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+#[cfg(all(feature = "arithmetic", feature = "der"))]
+impl<C> From<NormalizedSignature<C>> for der::Signature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+    der::MaxSize<C>: ArrayLength<u8>,
+    <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArrayLength<u8>,
+{
+    fn from(signature: NormalizedSignature<C>) -> der::Signature<C> {
+        signature.to_der()
+    }
+}
+
+/// Rejects signatures whose `s` component is high.
+#[cfg(all(feature = "arithmetic", feature = "der"))]
+impl<C> TryFrom<der::Signature<C>> for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+    der::MaxSize<C>: ArrayLength<u8>,
+    <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(signature: der::Signature<C>) -> Result<Self> {
+        Signature::try_from(signature).and_then(Self::try_from)
+    }
+}
+
+#[cfg(all(feature = "arithmetic", feature = "digest", feature = "hazmat"))]
+impl<C> signature::PrehashSignature for NormalizedSignature<C>
+where
+    C: CurveArithmetic + crate::hazmat::DigestPrimitive,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Digest = C::Digest;
+}
+
+#[cfg(all(feature = "arithmetic", feature = "serde"))]
+impl<C> Serialize for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "arithmetic", feature = "serde"))]
+impl<'de, C> Deserialize<'de> for NormalizedSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Signature::<C>::deserialize(deserializer)
+            .and_then(|signature| Self::try_from(signature).map_err(de::Error::custom))
+    }
+}
+
+#[cfg(all(test, feature = "arithmetic"))]
+mod tests {
+    use elliptic_curve::{dev::MockCurve, ff::PrimeField};
+    use hex_literal::hex;
+
+    type NormalizedSignature = crate::NormalizedSignature<MockCurve>;
+    type Signature = crate::Signature<MockCurve>;
+
+    /// Signature whose `s` component is in the upper half of the scalar field.
+    const HIGH_S_SIGNATURE: [u8; 64] = hex!(
+        "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac"
+        "f7ffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    );
+
+    #[test]
+    fn rejects_high_s() {
+        let signature = Signature::try_from(HIGH_S_SIGNATURE.as_ref()).unwrap();
+        assert!(NormalizedSignature::try_from(signature).is_err());
+        assert!(NormalizedSignature::try_from(HIGH_S_SIGNATURE.as_ref()).is_err());
+
+        let normalized = signature.normalize_s().unwrap();
+        assert_eq!(
+            NormalizedSignature::try_from(normalized)
+                .unwrap()
+                .signature(),
+            &normalized
+        );
+    }
+
+    #[test]
+    fn from_signature_normalizes_s() {
+        let signature = Signature::try_from(HIGH_S_SIGNATURE.as_ref()).unwrap();
+        let normalized = NormalizedSignature::from_signature(signature);
+        assert_ne!(normalized.signature(), &signature);
+        assert_eq!(normalized.split_bytes().0, signature.split_bytes().0);
+        assert_eq!((-normalized.s()).to_repr(), signature.s().to_repr());
+
+        // Normalizing a low-S signature is a no-op
+        let renormalized = NormalizedSignature::from_signature(normalized.into());
+        assert_eq!(renormalized, normalized);
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let signature = Signature::try_from(HIGH_S_SIGNATURE.as_ref()).unwrap();
+        let normalized = NormalizedSignature::from_signature(signature);
+        let bytes = normalized.to_bytes();
+        assert_eq!(NormalizedSignature::from_bytes(&bytes).unwrap(), normalized);
+    }
+}
@@ -1,6 +1,14 @@
 //! Public key recovery support.
 
-use crate::{Error, Result};
+use crate::{Error, Result, Signature, SignatureEncoding, SignatureSize};
+use core::{fmt, ops::Add};
+use elliptic_curve::{
+    generic_array::{
+        typenum::{Unsigned, U1},
+        ArrayLength, GenericArray,
+    },
+    PrimeCurve,
+};
 
 #[cfg(feature = "signing")]
 use {
@@ -19,21 +27,29 @@ use {
         sec1::{self, FromEncodedPoint, ToEncodedPoint},
         AffinePoint, FieldBytesEncoding, FieldBytesSize, Group, PrimeField, ProjectivePoint,
     },
-    signature::hazmat::PrehashVerifier,
+    signature::{hazmat::PrehashVerifier, DigestVerifier, Verifier},
+};
+
+#[cfg(feature = "arithmetic")]
+use {
+    crate::NormalizedSignature,
+    elliptic_curve::{CurveArithmetic, FieldBytes},
 };
 
 #[cfg(any(feature = "signing", feature = "verifying"))]
 use {
-    crate::{
-        hazmat::{bits2field, DigestPrimitive},
-        Signature, SignatureSize,
-    },
-    elliptic_curve::{
-        generic_array::ArrayLength, ops::Invert, CurveArithmetic, PrimeCurve, Scalar,
-    },
+    crate::hazmat::{bits2field, DigestPrimitive},
+    elliptic_curve::{ops::Invert, Scalar},
     signature::digest::Digest,
 };
 
+/// Size of a [`RecoverableSignature`] in bytes: a [`Signature`] plus one
+/// header byte.
+pub type RecoverableSignatureSize<C> = <SignatureSize<C> as Add<U1>>::Output;
+
+/// Fixed-size byte array containing a serialized [`RecoverableSignature`].
+pub type RecoverableSignatureBytes<C> = GenericArray<u8, RecoverableSignatureSize<C>>;
+
 /// Recovery IDs, a.k.a. "recid".
 ///
 /// This is an integer value `0`, `1`, `2`, or `3` included along with a
@@ -87,6 +103,117 @@ impl RecoveryId {
     }
 }
 
+/// Conversions between [`RecoveryId`] and the `v` values of Ethereum
+/// signatures.
+///
+/// Ethereum only uses the y-parity bit of the recovery ID, so converting a
+/// [`RecoveryId`] with a reduced x-coordinate into a `v` value is an error.
+impl RecoveryId {
+    /// Offset of legacy `v` values, i.e. `v = 27 + recid`.
+    const LEGACY_V_OFFSET: u64 = 27;
+
+    /// Offset of [EIP-155] `v` values, i.e. `v = 35 + 2 * chain_id + recid`.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    const EIP155_V_OFFSET: u64 = 35;
+
+    /// Get the y-parity bit, ensuring the x-coordinate wasn't reduced.
+    fn y_parity(self) -> Result<u64> {
+        if self.is_x_reduced() {
+            return Err(Error::new());
+        }
+
+        Ok(u64::from(self.0))
+    }
+
+    /// Create a [`RecoveryId`] from a y-parity bit.
+    fn from_y_parity(y_parity: u64) -> Result<Self> {
+        match y_parity {
+            0 => Ok(Self::new(false, false)),
+            1 => Ok(Self::new(true, false)),
+            _ => Err(Error::new()),
+        }
+    }
+
+    /// Convert a legacy (pre-EIP-155) `v` value of `27` or `28` into a
+    /// [`RecoveryId`].
+    pub fn from_legacy_v(v: u64) -> Result<Self> {
+        v.checked_sub(Self::LEGACY_V_OFFSET)
+            .ok_or_else(Error::new)
+            .and_then(Self::from_y_parity)
+    }
+
+    /// Convert this [`RecoveryId`] into a legacy (pre-EIP-155) `v` value of
+    /// `27` or `28`.
+    pub fn to_legacy_v(self) -> Result<u64> {
+        Ok(Self::LEGACY_V_OFFSET + self.y_parity()?)
+    }
+
+    /// Convert an [EIP-155] `v` value for the given chain ID into a
+    /// [`RecoveryId`].
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn from_eip155_v(v: u64, chain_id: u64) -> Result<Self> {
+        v.checked_sub(Self::eip155_v_offset(chain_id)?)
+            .ok_or_else(Error::new)
+            .and_then(Self::from_y_parity)
+    }
+
+    /// Convert this [`RecoveryId`] into an [EIP-155] `v` value for the given
+    /// chain ID.
+    ///
+    /// Returns an error if the `v` value doesn't fit into a `u64`.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn to_eip155_v(self, chain_id: u64) -> Result<u64> {
+        Self::eip155_v_offset(chain_id)?
+            .checked_add(self.y_parity()?)
+            .ok_or_else(Error::new)
+    }
+
+    /// Convert an [EIP-2930] `v` value, i.e. the y-parity bit used by typed
+    /// transactions, into a [`RecoveryId`].
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    pub fn from_eip2930_v(v: u64) -> Result<Self> {
+        Self::from_y_parity(v)
+    }
+
+    /// Convert this [`RecoveryId`] into an [EIP-2930] `v` value, i.e. the
+    /// y-parity bit used by typed transactions.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    pub fn to_eip2930_v(self) -> Result<u64> {
+        self.y_parity()
+    }
+
+    /// Convert any kind of Ethereum `v` value into a [`RecoveryId`]:
+    ///
+    /// - `0` or `1`: [EIP-2930] y-parity bit
+    /// - `27` or `28`: legacy `v` value
+    /// - `35 + 2 * chain_id` or `36 + 2 * chain_id`: [EIP-155] `v` value,
+    ///   only accepted if `chain_id` is given
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    pub fn from_ethereum_v(v: u64, chain_id: Option<u64>) -> Result<Self> {
+        match (v, chain_id) {
+            (0 | 1, _) => Self::from_eip2930_v(v),
+            (27 | 28, _) => Self::from_legacy_v(v),
+            (_, Some(chain_id)) => Self::from_eip155_v(v, chain_id),
+            (_, None) => Err(Error::new()),
+        }
+    }
+
+    /// Compute `35 + 2 * chain_id`, checking for overflow.
+    fn eip155_v_offset(chain_id: u64) -> Result<u64> {
+        chain_id
+            .checked_mul(2)
+            .and_then(|offset| offset.checked_add(Self::EIP155_V_OFFSET))
+            .ok_or_else(Error::new)
+    }
+}
+
 #[cfg(feature = "verifying")]
 impl RecoveryId {
     /// Given a public key, message, and signature, use trial recovery
@@ -169,6 +296,296 @@ impl From<RecoveryId> for u8 {
     }
 }
 
+/// ECDSA signature bundled with the [`RecoveryId`] needed to recover the
+/// signer's public key from it.
+///
+/// The canonical encoding of this type is the 65-byte (for 256-bit curves)
+/// "compact" form popularized by Bitcoin's message signing, i.e.
+/// `header || r || s`, where `header` is `27 + recid`, plus `4` if the
+/// signer's public key is meant to be serialized in SEC1 compressed form.
+///
+/// The `r || s || v` layout used by Ethereum, where `v` is `27 + recid`, is
+/// also supported via [`RecoverableSignature::from_ethereum_bytes`] and
+/// [`RecoverableSignature::to_ethereum_bytes`].
+#[derive(Clone, Eq, PartialEq)]
+pub struct RecoverableSignature<C: PrimeCurve> {
+    signature: Signature<C>,
+    recovery_id: RecoveryId,
+    is_compressed: bool,
+}
+
+impl<C> RecoverableSignature<C>
+where
+    C: PrimeCurve,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    /// Offset added to the [`RecoveryId`] to compute the header byte of the
+    /// compact encoding, and the `v` value of the Ethereum encoding.
+    const HEADER_OFFSET: u8 = 27;
+
+    /// Header bit indicating that the public key is compressed.
+    const COMPRESSED_FLAG: u8 = 4;
+
+    /// Create a new [`RecoverableSignature`] from a [`Signature`], its
+    /// [`RecoveryId`], and whether the signer's public key is serialized in
+    /// SEC1 compressed form.
+    pub fn new(signature: Signature<C>, recovery_id: RecoveryId, is_compressed: bool) -> Self {
+        Self {
+            signature,
+            recovery_id,
+            is_compressed,
+        }
+    }
+
+    /// Parse a signature from its compact `header || r || s` encoding.
+    ///
+    /// # Returns
+    /// - `Ok(signature)` if the header is in the range `27..=34` and the `r`
+    ///   and `s` components are both in the valid range `1..n`.
+    /// - `Err(err)` otherwise.
+    pub fn from_bytes(bytes: &RecoverableSignatureBytes<C>) -> Result<Self> {
+        let (header, signature) = bytes.split_first().ok_or_else(Error::new)?;
+        let id = header
+            .checked_sub(Self::HEADER_OFFSET)
+            .filter(|&id| id <= RecoveryId::MAX | Self::COMPRESSED_FLAG)
+            .ok_or_else(Error::new)?;
+
+        Ok(Self {
+            signature: Signature::from_slice(signature)?,
+            recovery_id: RecoveryId(id & RecoveryId::MAX),
+            is_compressed: id & Self::COMPRESSED_FLAG != 0,
+        })
+    }
+
+    /// Parse a signature from a byte slice containing its compact
+    /// `header || r || s` encoding.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != RecoverableSignatureSize::<C>::USIZE {
+            return Err(Error::new());
+        }
+
+        Self::from_bytes(GenericArray::from_slice(slice))
+    }
+
+    /// Parse a signature from its Ethereum `r || s || v` encoding.
+    ///
+    /// Both the raw recovery ID (`v` of `0` or `1`) and the legacy offset
+    /// form (`v` of `27` or `28`) are accepted. Public keys are always
+    /// uncompressed on Ethereum.
+    pub fn from_ethereum_bytes(slice: &[u8]) -> Result<Self> {
+        if slice.len() != RecoverableSignatureSize::<C>::USIZE {
+            return Err(Error::new());
+        }
+
+        let (&v, signature) = slice.split_last().ok_or_else(Error::new)?;
+        let recovery_id = match v {
+            0 | 1 => RecoveryId(v),
+            27 | 28 => RecoveryId(v - Self::HEADER_OFFSET),
+            _ => return Err(Error::new()),
+        };
+
+        Ok(Self {
+            signature: Signature::from_slice(signature)?,
+            recovery_id,
+            is_compressed: false,
+        })
+    }
+
+    /// Borrow the inner [`Signature`].
+    pub fn signature(&self) -> &Signature<C> {
+        &self.signature
+    }
+
+    /// Get the [`RecoveryId`] of this signature.
+    pub fn recovery_id(&self) -> RecoveryId {
+        self.recovery_id
+    }
+
+    /// Is the signer's public key serialized in SEC1 compressed form?
+    pub fn is_compressed(&self) -> bool {
+        self.is_compressed
+    }
+
+    /// Serialize this signature in its compact `header || r || s` encoding.
+    pub fn to_bytes(&self) -> RecoverableSignatureBytes<C> {
+        let mut bytes = RecoverableSignatureBytes::<C>::default();
+        let header = Self::HEADER_OFFSET + self.recovery_id.to_byte();
+        bytes[0] = if self.is_compressed {
+            header + Self::COMPRESSED_FLAG
+        } else {
+            header
+        };
+        bytes[1..].copy_from_slice(&self.signature.to_bytes());
+        bytes
+    }
+
+    /// Serialize this signature in its Ethereum `r || s || v` encoding, with
+    /// `v` of `27` or `28`.
+    ///
+    /// Returns an error if the recovery ID indicates a reduced x-coordinate,
+    /// which can't be represented in this form.
+    pub fn to_ethereum_bytes(&self) -> Result<RecoverableSignatureBytes<C>> {
+        if self.recovery_id.is_x_reduced() {
+            return Err(Error::new());
+        }
+
+        let mut bytes = RecoverableSignatureBytes::<C>::default();
+        let (v, signature) = bytes.split_last_mut().ok_or_else(Error::new)?;
+        signature.copy_from_slice(&self.signature.to_bytes());
+        *v = Self::HEADER_OFFSET + self.recovery_id.to_byte();
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> RecoverableSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    /// Normalize signature into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1] and required by Ethereum's
+    /// [EIP-2][2], flipping the y-parity bit of the [`RecoveryId`]
+    /// accordingly.
+    ///
+    /// Returns `None` if the signature was already normalized.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    /// [2]: https://eips.ethereum.org/EIPS/eip-2
+    pub fn normalize_s(&self) -> Option<Self> {
+        self.signature.normalize_s().map(|signature| Self {
+            signature,
+            recovery_id: RecoveryId(self.recovery_id.0 ^ 1),
+            is_compressed: self.is_compressed,
+        })
+    }
+
+    /// Create a [`RecoverableSignature`] from the `r`, `s`, and `v` values of
+    /// an Ethereum signature.
+    ///
+    /// `v` may be any of the forms accepted by
+    /// [`RecoveryId::from_ethereum_v`]. As required by [EIP-2], signatures
+    /// whose `s` component is high are rejected.
+    ///
+    /// [EIP-2]: https://eips.ethereum.org/EIPS/eip-2
+    pub fn from_rsv(
+        r: impl Into<FieldBytes<C>>,
+        s: impl Into<FieldBytes<C>>,
+        v: u64,
+        chain_id: Option<u64>,
+    ) -> Result<Self> {
+        let signature = NormalizedSignature::<C>::from_scalars(r, s)?;
+        let recovery_id = RecoveryId::from_ethereum_v(v, chain_id)?;
+        Ok(Self::new(signature.into(), recovery_id, false))
+    }
+}
+
+#[cfg(feature = "verifying")]
+impl<C> RecoverableSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    AffinePoint<C>:
+        DecompressPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C> + VerifyPrimitive<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    /// Recover the signer's [`VerifyingKey`] from the given message.
+    ///
+    /// The message is first hashed using this curve's [`DigestPrimitive`].
+    pub fn recover_from_msg(&self, msg: &[u8]) -> Result<VerifyingKey<C>>
+    where
+        C: DigestPrimitive,
+    {
+        VerifyingKey::recover_from_msg(msg, &self.signature, self.recovery_id)
+    }
+
+    /// Recover the signer's [`VerifyingKey`] from the given message
+    /// [`Digest`].
+    pub fn recover_from_digest<D>(&self, msg_digest: D) -> Result<VerifyingKey<C>>
+    where
+        D: Digest,
+    {
+        VerifyingKey::recover_from_digest(msg_digest, &self.signature, self.recovery_id)
+    }
+
+    /// Recover the signer's [`VerifyingKey`] from the given `prehash` of a
+    /// message.
+    pub fn recover_from_prehash(&self, prehash: &[u8]) -> Result<VerifyingKey<C>> {
+        VerifyingKey::recover_from_prehash(prehash, &self.signature, self.recovery_id)
+    }
+}
+
+impl<C> From<RecoverableSignature<C>> for RecoverableSignatureBytes<C>
+where
+    C: PrimeCurve,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: RecoverableSignature<C>) -> RecoverableSignatureBytes<C> {
+        signature.to_bytes()
+    }
+}
+
+impl<C> From<RecoverableSignature<C>> for Signature<C>
+where
+    C: PrimeCurve,
+{
+    fn from(signature: RecoverableSignature<C>) -> Signature<C> {
+        signature.signature
+    }
+}
+
+impl<C> From<RecoverableSignature<C>> for (Signature<C>, RecoveryId)
+where
+    C: PrimeCurve,
+{
+    fn from(signature: RecoverableSignature<C>) -> (Signature<C>, RecoveryId) {
+        (signature.signature, signature.recovery_id)
+    }
+}
+
+impl<C> SignatureEncoding for RecoverableSignature<C>
+where
+    C: PrimeCurve,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    type Repr = RecoverableSignatureBytes<C>;
+}
+
+impl<C> TryFrom<&[u8]> for RecoverableSignature<C>
+where
+    C: PrimeCurve,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self> {
+        Self::from_slice(slice)
+    }
+}
+
+impl<C> fmt::Debug for RecoverableSignature<C>
+where
+    C: PrimeCurve,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ecdsa::RecoverableSignature<{:?}>(", C::default())?;
+
+        for byte in self.to_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
 #[cfg(feature = "signing")]
 impl<C> SigningKey<C>
 where
@@ -275,6 +692,24 @@ where
         Self::recover_from_prehash(&msg_digest.finalize(), signature, recovery_id)
     }
 
+    /// Recover a [`VerifyingKey`] from the given `prehash` of a message and
+    /// the `r`, `s`, and `v` values of an Ethereum signature over it.
+    ///
+    /// See [`RecoverableSignature::from_rsv`] for the accepted values.
+    pub fn recover_from_rsv(
+        prehash: &[u8],
+        r: impl Into<FieldBytes<C>>,
+        s: impl Into<FieldBytes<C>>,
+        v: u64,
+        chain_id: Option<u64>,
+    ) -> Result<Self>
+    where
+        SignatureSize<C>: Add<U1>,
+        RecoverableSignatureSize<C>: ArrayLength<u8>,
+    {
+        RecoverableSignature::<C>::from_rsv(r, s, v, chain_id)?.recover_from_prehash(prehash)
+    }
+
     /// Recover a [`VerifyingKey`] from the given `prehash` of a message, the
     /// signature over that prehashed message, and a [`RecoveryId`].
     #[allow(non_snake_case)]
@@ -314,11 +749,142 @@ where
 
         Ok(vk)
     }
+
+    /// Recover every [`VerifyingKey`] for which the given signature over
+    /// `prehash` is valid, along with the corresponding [`RecoveryId`].
+    ///
+    /// Unlike [`RecoveryId::trial_recovery_from_prehash`], this doesn't
+    /// require knowing the signer's key in advance, which is useful e.g. for
+    /// matching signatures against a database of keys.
+    ///
+    /// Recovery IDs with a reduced x-coordinate are skipped when `r + n`
+    /// can't be a valid x-coordinate, i.e. when it is at least the field
+    /// modulus `p`, which is the case for all but a negligible fraction of
+    /// signatures on most curves.
+    pub fn recover_all_from_prehash<'a>(
+        prehash: &'a [u8],
+        signature: &'a Signature<C>,
+    ) -> impl Iterator<Item = (RecoveryId, Self)> + 'a {
+        // `recover_from_prehash` itself only rejects `r + n` if it overflows
+        // `C::Uint`. Values in `[p, 2^k)` are rejected by `decompress`, which
+        // fails to decode such a non-canonical field element
+        (0..=RecoveryId::MAX)
+            .map(RecoveryId)
+            .filter_map(move |recovery_id| {
+                Self::recover_from_prehash(prehash, signature, recovery_id)
+                    .ok()
+                    .map(|vk| (recovery_id, vk))
+            })
+    }
+}
+
+#[cfg(feature = "signing")]
+impl<C, D> DigestSigner<D, RecoverableSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    D: Digest,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_digest(&self, msg_digest: D) -> Result<RecoverableSignature<C>> {
+        self.sign_prehash(&msg_digest.finalize())
+    }
+}
+
+/// Signatures are produced with the compressed public key flag set.
+#[cfg(feature = "signing")]
+impl<C> PrehashSigner<RecoverableSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<RecoverableSignature<C>> {
+        let (signature, recovery_id) = self.sign_prehash_recoverable(prehash)?;
+        Ok(RecoverableSignature::new(signature, recovery_id, true))
+    }
+}
+
+#[cfg(feature = "signing")]
+impl<C> Signer<RecoverableSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<RecoverableSignature<C>> {
+        self.try_sign_digest(C::Digest::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "verifying")]
+impl<C, D> DigestVerifier<D, RecoverableSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    D: Digest,
+    AffinePoint<C>:
+        DecompressPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C> + VerifyPrimitive<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_digest(&self, msg_digest: D, signature: &RecoverableSignature<C>) -> Result<()> {
+        self.verify_prehash(&msg_digest.finalize(), signature)
+    }
+}
+
+/// Verifies the signature by recovering the signer's public key using the
+/// embedded [`RecoveryId`], and checking it matches this key.
+#[cfg(feature = "verifying")]
+impl<C> PrehashVerifier<RecoverableSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    AffinePoint<C>:
+        DecompressPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C> + VerifyPrimitive<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_prehash(&self, prehash: &[u8], signature: &RecoverableSignature<C>) -> Result<()> {
+        if &signature.recover_from_prehash(prehash)? == self {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+#[cfg(feature = "verifying")]
+impl<C> Verifier<RecoverableSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    AffinePoint<C>:
+        DecompressPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C> + VerifyPrimitive<C>,
+    FieldBytesSize<C>: sec1::ModulusSize,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    RecoverableSignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify(&self, msg: &[u8], signature: &RecoverableSignature<C>) -> Result<()> {
+        self.verify_digest(C::Digest::new_with_prefix(msg), signature)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::RecoveryId;
+    use elliptic_curve::dev::MockCurve;
+    use hex_literal::hex;
+
+    type RecoverableSignature = super::RecoverableSignature<MockCurve>;
+    type Signature = crate::Signature<MockCurve>;
+
+    const SIGNATURE: [u8; 64] = hex!(
+        "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac"
+        "8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903"
+    );
 
     #[test]
     fn new() {
@@ -354,4 +920,194 @@ mod tests {
         assert_eq!(RecoveryId::try_from(2).unwrap().is_y_odd(), false);
         assert_eq!(RecoveryId::try_from(3).unwrap().is_y_odd(), true);
     }
+
+    #[test]
+    fn compact_roundtrip() {
+        let signature = Signature::try_from(SIGNATURE.as_ref()).unwrap();
+
+        for header in 27u8..=34 {
+            let mut bytes = [header; 65];
+            bytes[1..].copy_from_slice(&SIGNATURE);
+
+            let recoverable = RecoverableSignature::try_from(bytes.as_ref()).unwrap();
+            assert_eq!(recoverable.signature(), &signature);
+            assert_eq!(recoverable.recovery_id().to_byte(), (header - 27) & 3);
+            assert_eq!(recoverable.is_compressed(), header >= 31);
+            assert_eq!(recoverable.to_bytes().as_slice(), bytes.as_ref());
+        }
+    }
+
+    #[test]
+    fn compact_invalid_header() {
+        for header in (0u8..27).chain(35..=255) {
+            let mut bytes = [header; 65];
+            bytes[1..].copy_from_slice(&SIGNATURE);
+            assert!(RecoverableSignature::try_from(bytes.as_ref()).is_err());
+        }
+
+        assert!(RecoverableSignature::try_from(SIGNATURE.as_ref()).is_err());
+    }
+
+    #[test]
+    fn ethereum_roundtrip() {
+        let signature = Signature::try_from(SIGNATURE.as_ref()).unwrap();
+
+        for (v, id) in [(0u8, 0u8), (1, 1), (27, 0), (28, 1)] {
+            let mut bytes = [v; 65];
+            bytes[..64].copy_from_slice(&SIGNATURE);
+
+            let recoverable = RecoverableSignature::from_ethereum_bytes(&bytes).unwrap();
+            assert_eq!(recoverable.signature(), &signature);
+            assert_eq!(recoverable.recovery_id().to_byte(), id);
+            assert!(!recoverable.is_compressed());
+
+            let encoded = recoverable.to_ethereum_bytes().unwrap();
+            assert_eq!(encoded[..64], SIGNATURE);
+            assert_eq!(encoded[64], 27 + id);
+        }
+
+        for v in [2u8, 3, 26, 29, 35, 37] {
+            let mut bytes = [v; 65];
+            bytes[..64].copy_from_slice(&SIGNATURE);
+            assert!(RecoverableSignature::from_ethereum_bytes(&bytes).is_err());
+        }
+
+        let reduced = RecoverableSignature::new(signature, RecoveryId::new(false, true), false);
+        assert!(reduced.to_ethereum_bytes().is_err());
+    }
+
+    #[test]
+    fn legacy_v() {
+        for (v, y_odd) in [(27, false), (28, true)] {
+            let recovery_id = RecoveryId::from_legacy_v(v).unwrap();
+            assert_eq!(recovery_id, RecoveryId::new(y_odd, false));
+            assert_eq!(recovery_id.to_legacy_v().unwrap(), v);
+        }
+
+        for v in [0, 1, 26, 29, 37] {
+            assert!(RecoveryId::from_legacy_v(v).is_err());
+        }
+
+        assert!(RecoveryId::new(false, true).to_legacy_v().is_err());
+    }
+
+    #[test]
+    fn eip155_v() {
+        // Ethereum mainnet
+        assert_eq!(RecoveryId::new(false, false).to_eip155_v(1).unwrap(), 37);
+        assert_eq!(RecoveryId::new(true, false).to_eip155_v(1).unwrap(), 38);
+        assert_eq!(
+            RecoveryId::from_eip155_v(37, 1).unwrap(),
+            RecoveryId::new(false, false)
+        );
+        assert_eq!(
+            RecoveryId::from_eip155_v(38, 1).unwrap(),
+            RecoveryId::new(true, false)
+        );
+
+        for v in [27, 35, 36, 39] {
+            assert!(RecoveryId::from_eip155_v(v, 1).is_err());
+        }
+
+        let max_chain_id = (u64::MAX - 36) / 2;
+        assert_eq!(
+            RecoveryId::new(true, false)
+                .to_eip155_v(max_chain_id)
+                .unwrap(),
+            u64::MAX - 1
+        );
+        assert!(RecoveryId::new(true, false)
+            .to_eip155_v(max_chain_id + 1)
+            .is_err());
+        assert!(RecoveryId::from_eip155_v(u64::MAX, u64::MAX).is_err());
+        assert!(RecoveryId::new(false, true).to_eip155_v(1).is_err());
+    }
+
+    #[test]
+    fn ethereum_v() {
+        for recovery_id in [RecoveryId::new(false, false), RecoveryId::new(true, false)] {
+            let v = recovery_id.to_eip2930_v().unwrap();
+            assert_eq!(RecoveryId::from_eip2930_v(v).unwrap(), recovery_id);
+            assert_eq!(RecoveryId::from_ethereum_v(v, None).unwrap(), recovery_id);
+
+            let v = recovery_id.to_legacy_v().unwrap();
+            assert_eq!(RecoveryId::from_ethereum_v(v, None).unwrap(), recovery_id);
+
+            let v = recovery_id.to_eip155_v(137).unwrap();
+            assert_eq!(
+                RecoveryId::from_ethereum_v(v, Some(137)).unwrap(),
+                recovery_id
+            );
+            assert!(RecoveryId::from_ethereum_v(v, None).is_err());
+            assert!(RecoveryId::from_ethereum_v(v, Some(1)).is_err());
+        }
+
+        assert!(RecoveryId::from_eip2930_v(2).is_err());
+        assert!(RecoveryId::new(true, true).to_eip2930_v().is_err());
+    }
+
+    #[cfg(feature = "arithmetic")]
+    #[test]
+    fn from_rsv() {
+        let signature = Signature::try_from(SIGNATURE.as_ref())
+            .unwrap()
+            .normalize_s()
+            .unwrap();
+        let (r, s) = signature.split_bytes();
+
+        for (v, chain_id, recovery_id) in [
+            (0, None, RecoveryId::new(false, false)),
+            (28, None, RecoveryId::new(true, false)),
+            (37, Some(1), RecoveryId::new(false, false)),
+            (310, Some(137), RecoveryId::new(true, false)),
+        ] {
+            let recoverable = RecoverableSignature::from_rsv(r, s, v, chain_id).unwrap();
+            assert_eq!(recoverable.signature(), &signature);
+            assert_eq!(recoverable.recovery_id(), recovery_id);
+            assert!(!recoverable.is_compressed());
+        }
+
+        // Invalid v and chain ID combinations
+        for (v, chain_id) in [
+            (2, None),
+            (26, None),
+            (37, None),
+            (37, Some(2)),
+            (38, Some(137)),
+        ] {
+            assert!(RecoverableSignature::from_rsv(r, s, v, chain_id).is_err());
+        }
+    }
+
+    #[cfg(feature = "arithmetic")]
+    #[test]
+    fn from_rsv_high_s() {
+        // EIP-2 forbids high-S signatures, even if v is valid
+        let (r, s) = Signature::try_from(SIGNATURE.as_ref())
+            .unwrap()
+            .split_bytes();
+        assert!(RecoverableSignature::from_rsv(r, s, 27, None).is_err());
+        assert!(RecoverableSignature::from_rsv(r, s, 37, Some(1)).is_err());
+    }
+
+    #[cfg(feature = "arithmetic")]
+    #[test]
+    fn normalize_s() {
+        use elliptic_curve::ff::PrimeField;
+
+        let high_s = hex!(
+            "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac"
+            "f7ffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+        );
+        let signature = Signature::try_from(high_s.as_ref()).unwrap();
+        let recoverable = RecoverableSignature::new(signature, RecoveryId::new(true, true), false);
+
+        let normalized = recoverable.normalize_s().unwrap();
+        assert_eq!(normalized.recovery_id(), RecoveryId::new(false, true));
+        assert_eq!(
+            (-normalized.signature().s()).to_repr(),
+            signature.s().to_repr()
+        );
+        assert!(normalized.normalize_s().is_none());
+    }
 }
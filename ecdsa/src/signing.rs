@@ -3,17 +3,17 @@
 use crate::{
     ecdsa_oid_for_digest,
     hazmat::{bits2field, DigestPrimitive, SignPrimitive},
-    Error, Result, Signature, SignatureSize, SignatureWithOid,
+    Error, NormalizedSignature, Result, Signature, SignatureSize, SignatureWithOid,
 };
 use core::fmt::{self, Debug};
-use digest::{const_oid::AssociatedOid, Digest, FixedOutput};
+use digest::{const_oid::AssociatedOid, core_api::BlockSizeUser, Digest, FixedOutputReset};
 use elliptic_curve::{
     generic_array::ArrayLength,
     group::ff::PrimeField,
     ops::Invert,
     subtle::{Choice, ConstantTimeEq, CtOption},
     zeroize::{Zeroize, ZeroizeOnDrop},
-    CurveArithmetic, FieldBytes, FieldBytesSize, NonZeroScalar, PrimeCurve, Scalar, SecretKey,
+    CurveArithmetic, FieldBytes, NonZeroScalar, PrimeCurve, Scalar, SecretKey,
 };
 use signature::{
     hazmat::{PrehashSigner, RandomizedPrehashSigner},
@@ -22,7 +22,7 @@ use signature::{
 };
 
 #[cfg(feature = "der")]
-use {crate::der, core::ops::Add};
+use {crate::der, core::ops::Add, elliptic_curve::FieldBytesSize};
 
 #[cfg(feature = "pem")]
 use {
@@ -123,6 +123,87 @@ where
     pub fn verifying_key(&self) -> &VerifyingKey<C> {
         &self.verifying_key
     }
+
+    /// Sign the given message digest using a deterministic ephemeral scalar
+    /// (`k`) computed using the algorithm described in [RFC6979 § 3.2], with
+    /// `D` used both as the message digest and as the `HMAC_DRBG` digest.
+    ///
+    /// Unlike the [`DigestSigner`] impl, which always uses the curve's
+    /// [`DigestPrimitive::Digest`] for `HMAC_DRBG`, this allows any pairing
+    /// of digest and curve, e.g. P-256 with SHA-512.
+    ///
+    /// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
+    pub fn try_sign_digest_rfc6979<D>(&self, msg_digest: D) -> Result<Signature<C>>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        self.sign_prehash_rfc6979::<D>(&msg_digest.finalize_fixed())
+    }
+
+    /// Sign the given message prehash using a deterministic ephemeral scalar
+    /// (`k`) computed using the algorithm described in [RFC6979 § 3.2], with
+    /// `D` used as the `HMAC_DRBG` digest.
+    ///
+    /// `D` should be the digest which was used to compute `prehash`.
+    ///
+    /// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
+    pub fn sign_prehash_rfc6979<D>(&self, prehash: &[u8]) -> Result<Signature<C>>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        let z = bits2field::<C>(prehash)?;
+        Ok(self
+            .secret_scalar
+            .try_sign_prehashed_rfc6979::<D>(&z, &[])?
+            .0)
+    }
+
+    /// Sign the given message digest using a hedged ephemeral scalar (`k`)
+    /// computed using the construction described in
+    /// [draft-irtf-cfrg-det-sigs-with-noise], with `D` used both as the
+    /// message digest and as the `HMAC_DRBG` digest.
+    ///
+    /// This mixes fresh randomness from `rng` into the [RFC6979] derivation of
+    /// `k`, which provides resistance to fault attacks against deterministic
+    /// signatures while remaining secure if `rng` is weak.
+    ///
+    /// [draft-irtf-cfrg-det-sigs-with-noise]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-det-sigs-with-noise
+    /// [RFC6979]: https://tools.ietf.org/html/rfc6979
+    pub fn try_sign_digest_hedged<D>(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: D,
+    ) -> Result<Signature<C>>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        self.sign_prehash_hedged::<D>(rng, &msg_digest.finalize_fixed())
+    }
+
+    /// Sign the given message prehash using a hedged ephemeral scalar (`k`)
+    /// computed using the construction described in
+    /// [draft-irtf-cfrg-det-sigs-with-noise], with `D` used as the
+    /// `HMAC_DRBG` digest.
+    ///
+    /// `D` should be the digest which was used to compute `prehash`.
+    ///
+    /// [draft-irtf-cfrg-det-sigs-with-noise]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-det-sigs-with-noise
+    pub fn sign_prehash_hedged<D>(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature<C>>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        let z = bits2field::<C>(prehash)?;
+        let mut noise = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut noise);
+        Ok(self
+            .secret_scalar
+            .try_sign_prehashed_hedged::<D>(&z, &noise)?
+            .0)
+    }
 }
 
 //
@@ -130,18 +211,19 @@ where
 //
 
 /// Sign message digest using a deterministic ephemeral scalar (`k`)
-/// computed using the algorithm described in [RFC6979 § 3.2].
+/// computed using the algorithm described in [RFC6979 § 3.2], with `D` used
+/// as the `HMAC_DRBG` digest.
 ///
 /// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
 impl<C, D> DigestSigner<D, Signature<C>> for SigningKey<C>
 where
     C: PrimeCurve + CurveArithmetic + DigestPrimitive,
-    D: Digest + FixedOutput<OutputSize = FieldBytesSize<C>>,
+    D: Digest + BlockSizeUser + FixedOutputReset,
     Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
     SignatureSize<C>: ArrayLength<u8>,
 {
     fn try_sign_digest(&self, msg_digest: D) -> Result<Signature<C>> {
-        self.sign_prehash(&msg_digest.finalize_fixed())
+        self.sign_prehash_rfc6979::<D>(&msg_digest.finalize_fixed())
     }
 }
 
@@ -179,10 +261,15 @@ where
     }
 }
 
+/// Sign message digest using an ephemeral scalar (`k`) computed using the
+/// algorithm described in [RFC6979 § 3.2] with added randomness, with `D`
+/// used as the `HMAC_DRBG` digest.
+///
+/// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
 impl<C, D> RandomizedDigestSigner<D, Signature<C>> for SigningKey<C>
 where
     C: PrimeCurve + CurveArithmetic + DigestPrimitive,
-    D: Digest + FixedOutput<OutputSize = FieldBytesSize<C>>,
+    D: Digest + BlockSizeUser + FixedOutputReset,
     Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
     SignatureSize<C>: ArrayLength<u8>,
 {
@@ -191,7 +278,13 @@ where
         rng: &mut impl CryptoRngCore,
         msg_digest: D,
     ) -> Result<Signature<C>> {
-        self.sign_prehash_with_rng(rng, &msg_digest.finalize_fixed())
+        let z = bits2field::<C>(&msg_digest.finalize_fixed())?;
+        let mut ad = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut ad);
+        Ok(self
+            .secret_scalar
+            .try_sign_prehashed_rfc6979::<D>(&z, &ad)?
+            .0)
     }
 }
 
@@ -231,7 +324,7 @@ where
 impl<C, D> DigestSigner<D, SignatureWithOid<C>> for SigningKey<C>
 where
     C: PrimeCurve + CurveArithmetic + DigestPrimitive,
-    D: AssociatedOid + Digest + FixedOutput<OutputSize = FieldBytesSize<C>>,
+    D: AssociatedOid + Digest + BlockSizeUser + FixedOutputReset,
     Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
     SignatureSize<C>: ArrayLength<u8>,
 {
@@ -254,6 +347,97 @@ where
     }
 }
 
+/// Sign message digest, producing a signature whose `s` component is always
+/// normalized to be "low".
+impl<C, D> DigestSigner<D, NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_digest(&self, msg_digest: D) -> Result<NormalizedSignature<C>> {
+        DigestSigner::<D, Signature<C>>::try_sign_digest(self, msg_digest)
+            .map(NormalizedSignature::from_signature)
+    }
+}
+
+/// Sign message prehash, producing a signature whose `s` component is always
+/// normalized to be "low".
+impl<C> PrehashSigner<NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<NormalizedSignature<C>> {
+        PrehashSigner::<Signature<C>>::sign_prehash(self, prehash)
+            .map(NormalizedSignature::from_signature)
+    }
+}
+
+/// Sign message, producing a signature whose `s` component is always
+/// normalized to be "low".
+impl<C> Signer<NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<NormalizedSignature<C>> {
+        Signer::<Signature<C>>::try_sign(self, msg).map(NormalizedSignature::from_signature)
+    }
+}
+
+impl<C, D> RandomizedDigestSigner<D, NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: D,
+    ) -> Result<NormalizedSignature<C>> {
+        RandomizedDigestSigner::<D, Signature<C>>::try_sign_digest_with_rng(self, rng, msg_digest)
+            .map(NormalizedSignature::from_signature)
+    }
+}
+
+impl<C> RandomizedPrehashSigner<NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<NormalizedSignature<C>> {
+        RandomizedPrehashSigner::<Signature<C>>::sign_prehash_with_rng(self, rng, prehash)
+            .map(NormalizedSignature::from_signature)
+    }
+}
+
+impl<C> RandomizedSigner<NormalizedSignature<C>> for SigningKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<NormalizedSignature<C>> {
+        RandomizedSigner::<Signature<C>>::try_sign_with_rng(self, rng, msg)
+            .map(NormalizedSignature::from_signature)
+    }
+}
+
 #[cfg(feature = "der")]
 impl<C> PrehashSigner<der::Signature<C>> for SigningKey<C>
 where
@@ -286,7 +470,7 @@ where
 impl<C, D> RandomizedDigestSigner<D, der::Signature<C>> for SigningKey<C>
 where
     C: PrimeCurve + CurveArithmetic + DigestPrimitive,
-    D: Digest + FixedOutput<OutputSize = FieldBytesSize<C>>,
+    D: Digest + BlockSizeUser + FixedOutputReset,
     Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + SignPrimitive<C>,
     SignatureSize<C>: ArrayLength<u8>,
     der::MaxSize<C>: ArrayLength<u8>,
@@ -2,7 +2,7 @@
 
 use crate::{
     hazmat::{bits2field, DigestPrimitive, VerifyPrimitive},
-    Error, Result, Signature, SignatureSize,
+    Error, NormalizedSignature, Result, Signature, SignatureSize,
 };
 use core::{cmp::Ordering, fmt::Debug};
 use elliptic_curve::{
@@ -195,6 +195,52 @@ where
     }
 }
 
+/// Verify a message digest against a signature whose `s` component is
+/// guaranteed to be "low".
+///
+/// High-S signatures are rejected when parsing a [`NormalizedSignature`].
+impl<C, D> DigestVerifier<D, NormalizedSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    D: Digest + FixedOutput<OutputSize = FieldBytesSize<C>>,
+    AffinePoint<C>: VerifyPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_digest(&self, msg_digest: D, signature: &NormalizedSignature<C>) -> Result<()> {
+        DigestVerifier::<D, Signature<C>>::verify_digest(self, msg_digest, signature.signature())
+    }
+}
+
+/// Verify a message prehash against a signature whose `s` component is
+/// guaranteed to be "low".
+///
+/// High-S signatures are rejected when parsing a [`NormalizedSignature`].
+impl<C> PrehashVerifier<NormalizedSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    AffinePoint<C>: VerifyPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_prehash(&self, prehash: &[u8], signature: &NormalizedSignature<C>) -> Result<()> {
+        PrehashVerifier::<Signature<C>>::verify_prehash(self, prehash, signature.signature())
+    }
+}
+
+/// Verify a message against a signature whose `s` component is guaranteed to
+/// be "low".
+///
+/// High-S signatures are rejected when parsing a [`NormalizedSignature`].
+impl<C> Verifier<NormalizedSignature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + CurveArithmetic + DigestPrimitive,
+    AffinePoint<C>: VerifyPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify(&self, msg: &[u8], signature: &NormalizedSignature<C>) -> Result<()> {
+        Verifier::<Signature<C>>::verify(self, msg, signature.signature())
+    }
+}
+
 #[cfg(feature = "der")]
 impl<C, D> DigestVerifier<D, der::Signature<C>> for VerifyingKey<C>
 where
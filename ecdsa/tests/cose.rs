@@ -0,0 +1,157 @@
+//! COSE tests which use the NIST P-256 and P-384 curves
+
+#![cfg(all(feature = "cose", feature = "signing"))]
+
+use ecdsa::cose::{algorithm, curve};
+use hex_literal::hex;
+use p256::NistP256;
+use p384::NistP384;
+
+type SigningKey = ecdsa::SigningKey<NistP256>;
+type VerifyingKey = ecdsa::VerifyingKey<NistP256>;
+
+/// P-256 private key `11` from RFC 9052 Appendix C.7.2.
+const COSE_KEY: [u8; 110] = hex!(
+    "a501022001215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff"
+    "22582020138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e"
+    "23582057c92077664146e876760c9520d054aa93c3afb04e306705db6090308507b4d3"
+);
+
+/// Public part of [`COSE_KEY`], as in RFC 9052 Appendix C.7.1.
+const COSE_PUBLIC_KEY: [u8; 75] = hex!(
+    "a401022001215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff"
+    "22582020138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e"
+);
+
+/// `COSE_Sign1` example from RFC 9052 Appendix C.2.1, signed with key `11`.
+const COSE_SIGN1: [u8; 98] = hex!(
+    "d28443a10126a10442313154546869732069732074686520636f6e74656e742e5840"
+    "8eb33e4ca31d1c465ab05aac34cc6b23d58fef5c083106c4d25a91aef0b0117e"
+    "2af9a291aa32e14ab834dc56ed2a223444547e01f11d3b0916e5a4c345cacb36"
+);
+
+/// Private key of the P-256 example from RFC 6979 Appendix A.2.5.
+const OTHER_SECRET_KEY: [u8; 32] =
+    hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+#[test]
+fn algorithms() {
+    assert_eq!(curve::<NistP256>().unwrap(), 1);
+    assert_eq!(algorithm::<NistP256>().unwrap(), -7);
+    assert_eq!(curve::<NistP384>().unwrap(), 2);
+    assert_eq!(algorithm::<NistP384>().unwrap(), -35);
+}
+
+#[test]
+fn cose_key_roundtrip() {
+    let signing_key = SigningKey::from_cose_key(&COSE_KEY).unwrap();
+    assert_eq!(signing_key.to_cose_key().unwrap().as_slice(), COSE_KEY);
+    assert_eq!(
+        signing_key.verifying_key().to_cose_key().unwrap(),
+        COSE_PUBLIC_KEY
+    );
+    assert_eq!(
+        VerifyingKey::from_cose_key(&COSE_PUBLIC_KEY).unwrap(),
+        *signing_key.verifying_key()
+    );
+
+    // The public key is optional
+    let d_only = [&hex!("a301022001")[..], &COSE_KEY[75..]].concat();
+    assert_eq!(SigningKey::from_cose_key(&d_only).unwrap(), signing_key);
+    assert!(VerifyingKey::from_cose_key(&d_only).is_err());
+
+    // Public keys have no private key
+    assert!(SigningKey::from_cose_key(&COSE_PUBLIC_KEY).is_err());
+}
+
+#[test]
+fn cose_key_public_key_mismatch() {
+    let other_key = SigningKey::from_slice(&OTHER_SECRET_KEY)
+        .unwrap()
+        .to_cose_key()
+        .unwrap();
+
+    // d of key `11` along with the public key of another key
+    let mut key = other_key.to_vec();
+    key[75..].copy_from_slice(&COSE_KEY[75..]);
+    assert!(SigningKey::from_cose_key(&key).is_err());
+
+    // Compressed public key with the wrong sign bit
+    let y_is_odd = COSE_KEY[74] & 1 == 1;
+    let compressed = [
+        &COSE_KEY[..40],
+        &[0x22, if y_is_odd { 0xf4 } else { 0xf5 }],
+        &COSE_KEY[75..],
+    ]
+    .concat();
+    assert!(SigningKey::from_cose_key(&compressed).is_err());
+
+    // ...and with the right one
+    let compressed = [
+        &COSE_KEY[..40],
+        &[0x22, if y_is_odd { 0xf5 } else { 0xf4 }],
+        &COSE_KEY[75..],
+    ]
+    .concat();
+    assert!(SigningKey::from_cose_key(&compressed).is_ok());
+}
+
+#[test]
+fn verify_rfc9052_example() {
+    let verifying_key = VerifyingKey::from_cose_key(&COSE_PUBLIC_KEY).unwrap();
+    assert_eq!(
+        verifying_key.verify_cose_sign1(&COSE_SIGN1, b"").unwrap(),
+        b"This is the content."
+    );
+    assert!(verifying_key
+        .verify_cose_sign1(&COSE_SIGN1, b"aad")
+        .is_err());
+
+    // Modified payload
+    let mut cose_sign1 = COSE_SIGN1;
+    cose_sign1[12] ^= 1;
+    assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+
+    // Wrong key
+    let other_key = SigningKey::from_slice(&OTHER_SECRET_KEY).unwrap();
+    assert!(other_key
+        .verifying_key()
+        .verify_cose_sign1(&COSE_SIGN1, b"")
+        .is_err());
+
+    // Wrong curve
+    let other_key = ecdsa::SigningKey::<NistP384>::from_slice(&[1; 48]).unwrap();
+    assert!(other_key
+        .verifying_key()
+        .verify_cose_sign1(&COSE_SIGN1, b"")
+        .is_err());
+}
+
+#[test]
+fn sign_verify_cose_sign1() {
+    let signing_key = SigningKey::from_cose_key(&COSE_KEY).unwrap();
+    let verifying_key = signing_key.verifying_key();
+    let payload = b"This is the content.";
+
+    let cose_sign1 = signing_key.sign_cose_sign1(payload, b"").unwrap();
+    assert_eq!(
+        cose_sign1[..30],
+        hex!("d28443a10126a054546869732069732074686520636f6e74656e742e5840")
+    );
+    assert_eq!(
+        verifying_key.verify_cose_sign1(&cose_sign1, b"").unwrap(),
+        payload
+    );
+    assert!(verifying_key
+        .verify_cose_sign1(&cose_sign1, b"aad")
+        .is_err());
+
+    let cose_sign1 = signing_key.sign_cose_sign1(payload, b"aad").unwrap();
+    assert_eq!(
+        verifying_key
+            .verify_cose_sign1(&cose_sign1, b"aad")
+            .unwrap(),
+        payload
+    );
+    assert!(verifying_key.verify_cose_sign1(&cose_sign1, b"").is_err());
+}
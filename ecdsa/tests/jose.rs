@@ -0,0 +1,90 @@
+//! JOSE tests which use the NIST P-256 and P-384 curves
+
+#![cfg(all(feature = "jose", feature = "signing"))]
+
+use ecdsa::jose::algorithm;
+use p256::NistP256;
+use p384::NistP384;
+
+/// ES256 example from RFC 7515 Appendix A.3.
+const JWS: &str = concat!(
+    "eyJhbGciOiJFUzI1NiJ9",
+    ".",
+    "eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ",
+    ".",
+    "DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q",
+);
+
+/// Public key of the example from RFC 7515 Appendix A.3.
+const JWK: &str = r#"{"kty":"EC","crv":"P-256","x":"f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU","y":"x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"}"#;
+
+/// Payload of the example from RFC 7515 Appendix A.3.
+const PAYLOAD: &[u8] =
+    b"{\"iss\":\"joe\",\r\n \"exp\":1300819380,\r\n \"http://example.com/is_root\":true}";
+
+/// Private key of the P-256 example from RFC 6979 Appendix A.2.5.
+const P256_SECRET_KEY: [u8; 32] =
+    hex_literal::hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+/// Private key of the P-384 example from RFC 6979 Appendix A.2.6.
+const P384_SECRET_KEY: [u8; 48] = hex_literal::hex!(
+    "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8"
+    "96d5724e4c70a825f872c9ea60d2edf5"
+);
+
+#[test]
+fn algorithms() {
+    assert_eq!(algorithm::<NistP256>().unwrap(), "ES256");
+    assert_eq!(algorithm::<NistP384>().unwrap(), "ES384");
+}
+
+#[test]
+fn verify_rfc7515_example() {
+    let verifying_key = ecdsa::VerifyingKey::<NistP256>::from_jwk_str(JWK).unwrap();
+    assert_eq!(verifying_key.verify_jws(JWS).unwrap(), PAYLOAD);
+
+    // Modified payload
+    let (header, rest) = JWS.split_once('.').unwrap();
+    let jws = format!("{header}.Z{}", &rest[1..]);
+    assert!(verifying_key.verify_jws(&jws).is_err());
+
+    // Wrong key
+    let signing_key = ecdsa::SigningKey::<NistP256>::from_slice(&P256_SECRET_KEY).unwrap();
+    assert!(signing_key.verifying_key().verify_jws(JWS).is_err());
+
+    // Wrong curve
+    let signing_key = ecdsa::SigningKey::<NistP384>::from_slice(&P384_SECRET_KEY).unwrap();
+    assert!(signing_key.verifying_key().verify_jws(JWS).is_err());
+}
+
+#[test]
+fn sign_verify_jws_p256() {
+    let signing_key = ecdsa::SigningKey::<NistP256>::from_slice(&P256_SECRET_KEY).unwrap();
+    let jws = signing_key.sign_jws(PAYLOAD).unwrap();
+
+    let (signing_input, _) = jws.rsplit_once('.').unwrap();
+    assert_eq!(signing_input, JWS.rsplit_once('.').unwrap().0);
+    assert_eq!(
+        signing_key.verifying_key().verify_jws(&jws).unwrap(),
+        PAYLOAD
+    );
+
+    let other_key = ecdsa::VerifyingKey::<NistP256>::from_jwk_str(JWK).unwrap();
+    assert!(other_key.verify_jws(&jws).is_err());
+}
+
+#[test]
+fn sign_verify_jws_p384() {
+    let signing_key = ecdsa::SigningKey::<NistP384>::from_slice(&P384_SECRET_KEY).unwrap();
+    let jws = signing_key.sign_jws(b"payload").unwrap();
+
+    assert!(jws.starts_with("eyJhbGciOiJFUzM4NCJ9.cGF5bG9hZA."));
+    assert_eq!(
+        signing_key.verifying_key().verify_jws(&jws).unwrap(),
+        b"payload"
+    );
+
+    // ES384 signatures are rejected by P-256 keys
+    let verifying_key = ecdsa::VerifyingKey::<NistP256>::from_jwk_str(JWK).unwrap();
+    assert!(verifying_key.verify_jws(&jws).is_err());
+}
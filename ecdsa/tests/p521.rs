@@ -0,0 +1,141 @@
+//! RFC 6979 tests which use the NIST P-521 curve, whose order isn't a multiple of 8 bits
+
+#![cfg(all(feature = "signing", feature = "verifying"))]
+
+use ecdsa::hazmat::bits2field;
+use hex_literal::hex;
+use p521::{FieldBytes, NistP521};
+use sha2::{
+    digest::{core_api::BlockSizeUser, FixedOutputReset},
+    Digest, Sha256, Sha384, Sha512,
+};
+use signature::hazmat::PrehashVerifier;
+
+type Signature = ecdsa::Signature<NistP521>;
+type SigningKey = ecdsa::SigningKey<NistP521>;
+type VerifyingKey = ecdsa::VerifyingKey<NistP521>;
+
+/// Private key from RFC 6979 Appendix A.2.7.
+const SECRET_KEY: [u8; 66] = hex!(
+    "00FAD06DAA62BA3B25D2FB40133DA757205DE67F5BB0018FEE8C86E1B68C7E75CA"
+    "A896EB32F1F47C70855836A6D16FCC1466F6D8FBEC67DB89EC0C08B0E996B83538"
+);
+
+/// Uncompressed public key from RFC 6979 Appendix A.2.7.
+const PUBLIC_KEY: [u8; 133] = hex!(
+    "04"
+    "01894550D0785932E00EAA23B694F213F8C3121F86DC97A04E5A7167DB4E5BCD37"
+    "1123D46E45DB6B5D5370A7F20FB633155D38FFA16D2BD761DCAC474B9A2F5023A4"
+    "00493101C962CD4D2FDDF782285E64584139C2F91B47F87FF82354D6630F746A28"
+    "A0DB25741B5B34A828008B22ACC23F924FAAFBD4D33F81EA66956DFEAA2BFDFCF5"
+);
+
+/// Sign `msg` with the digest `D` and check the signature against the
+/// expected (r, s), then verify it.
+fn check<D: Digest + BlockSizeUser + FixedOutputReset>(msg: &[u8], r: [u8; 66], s: [u8; 66]) {
+    let signing_key = SigningKey::from_slice(&SECRET_KEY).unwrap();
+    let signature = signing_key
+        .try_sign_digest_rfc6979::<D>(D::new_with_prefix(msg))
+        .unwrap();
+    let (r, s) = (
+        FieldBytes::clone_from_slice(&r),
+        FieldBytes::clone_from_slice(&s),
+    );
+    assert_eq!(signature, Signature::from_scalars(r, s).unwrap());
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(&PUBLIC_KEY).unwrap();
+    assert_eq!(*signing_key.verifying_key(), verifying_key);
+    assert!(verifying_key
+        .verify_prehash(&D::digest(msg), &signature)
+        .is_ok());
+}
+
+/// RFC 6979 Appendix A.2.7
+///
+/// The SHA-224 and SHA-256 cases are omitted, as [`bits2field`] rejects
+/// digests shorter than half the field size.
+#[test]
+fn rfc6979_p521() {
+    check::<Sha384>(
+        b"sample",
+        hex!(
+            "01EA842A0E17D2DE4F92C15315C63DDF72685C18195C2BB95E572B9C5136CA4B4B"
+            "576AD712A52BE9730627D16054BA40CC0B8D3FF035B12AE75168397F5D50C67451"
+        ),
+        hex!(
+            "01F21A3CEE066E1961025FB048BD5FE2B7924D0CD797BABE0A83B66F1E35EEAF5F"
+            "DE143FA85DC394A7DEE766523393784484BDF3E00114A1C857CDE1AA203DB65D61"
+        ),
+    );
+    check::<Sha384>(
+        b"test",
+        hex!(
+            "014BEE21A18B6D8B3C93FAB08D43E739707953244FDBE924FA926D76669E7AC8C8"
+            "9DF62ED8975C2D8397A65A49DCC09F6B0AC62272741924D479354D74FF6075578C"
+        ),
+        hex!(
+            "0133330865C067A0EAF72362A65E2D7BC4E461E8C8995C3B6226A21BD1AA78F0ED"
+            "94FE536A0DCA35534F0CD1510C41525D163FE9D74D134881E35141ED5E8E95B979"
+        ),
+    );
+    check::<Sha512>(
+        b"sample",
+        hex!(
+            "00C328FAFCBD79DD77850370C46325D987CB525569FB63C5D3BC53950E6D4C5F17"
+            "4E25A1EE9017B5D450606ADD152B534931D7D4E8455CC91F9B15BF05EC36E377FA"
+        ),
+        hex!(
+            "00617CCE7CF5064806C467F678D3B4080D6F1CC50AF26CA209417308281B68AF28"
+            "2623EAA63E5B5C0723D8B8C37FF0777B1A20F8CCB1DCCC43997F1EE0E44DA4A67A"
+        ),
+    );
+    check::<Sha512>(
+        b"test",
+        hex!(
+            "013E99020ABF5CEE7525D16B69B229652AB6BDF2AFFCAEF38773B4B7D08725F10C"
+            "DB93482FDCC54EDCEE91ECA4166B2A7C6265EF0CE2BD7051B7CEF945BABD47EE6D"
+        ),
+        hex!(
+            "01FBD0013C674AA79CB39849527916CE301C66EA7CE8B80682786AD60F98F7E78A"
+            "19CA69EFF5C57400E3B3A0AD66CE0978214D13BAF4E9AC60752F7B155E2DE4DCE3"
+        ),
+    );
+}
+
+#[test]
+fn bits2field_truncates_to_qlen_bits() {
+    // Inputs which are longer than qlen keep only their leftmost qlen bits
+    let field_bytes = bits2field::<NistP521>(&[0xFF; 66]).unwrap();
+    assert_eq!(field_bytes[0], 0x01);
+    assert_eq!(field_bytes[1..], [0xFF; 65]);
+
+    // ...so the low 7 bits of a 66-byte input are discarded
+    let mut bits = [0; 66];
+    bits[65] = 0x7F;
+    assert_eq!(bits2field::<NistP521>(&bits).unwrap()[..], [0; 66]);
+
+    bits[65] = 0x80;
+    let field_bytes = bits2field::<NistP521>(&bits).unwrap();
+    assert_eq!(field_bytes[..65], [0; 65]);
+    assert_eq!(field_bytes[65], 0x01);
+
+    bits[0] = 0x80;
+    let field_bytes = bits2field::<NistP521>(&bits).unwrap();
+    assert_eq!(field_bytes[0], 0x01);
+    assert_eq!(field_bytes[1..65], [0; 64]);
+    assert_eq!(field_bytes[65], 0x01);
+
+    // Inputs which are shorter than half the field size are rejected
+    assert!(bits2field::<NistP521>(&Sha256::digest(b"sample")).is_err());
+
+    // Other inputs which are shorter than qlen are left-padded with zeroes
+    let field_bytes = bits2field::<NistP521>(&[0xFF; 64]).unwrap();
+    assert_eq!(field_bytes[..2], [0; 2]);
+    assert_eq!(field_bytes[2..], [0xFF; 64]);
+
+    // Longer inputs are truncated to the field size first
+    assert_eq!(
+        bits2field::<NistP521>(&[0xFF; 80]).unwrap(),
+        bits2field::<NistP521>(&[0xFF; 66]).unwrap()
+    );
+}
@@ -0,0 +1,82 @@
+//! Public key recovery tests which use the NIST P-256 curve
+
+#![cfg(all(feature = "signing", feature = "verifying"))]
+
+use ecdsa::RecoveryId;
+use hex_literal::hex;
+use p256::NistP256;
+use sha2::{Digest, Sha256};
+use signature::hazmat::PrehashVerifier;
+
+type Signature = ecdsa::Signature<NistP256>;
+type SigningKey = ecdsa::SigningKey<NistP256>;
+type VerifyingKey = ecdsa::VerifyingKey<NistP256>;
+
+/// Private key of the P-256 example from RFC 6979 Appendix A.2.5.
+const SECRET_KEY: [u8; 32] =
+    hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+#[test]
+fn recover_all_from_prehash() {
+    let signing_key = SigningKey::from_slice(&SECRET_KEY).unwrap();
+    let prehash = Sha256::digest(b"sample");
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+
+    let recovered: Vec<_> = VerifyingKey::recover_all_from_prehash(&prehash, &signature).collect();
+    assert!(recovered.contains(&(recovery_id, *signing_key.verifying_key())));
+
+    // Every recovered key is a valid signer of the prehash
+    for (recovery_id, verifying_key) in &recovered {
+        assert!(verifying_key.verify_prehash(&prehash, &signature).is_ok());
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&prehash, &signature, *recovery_id).unwrap(),
+            *verifying_key
+        );
+    }
+}
+
+#[test]
+fn recover_all_from_prehash_reduced_x() {
+    // Both 6 and 6 + n are x-coordinates of points on the curve, so every
+    // recovery ID yields a key
+    let signature = Signature::from_scalars(
+        hex!("0000000000000000000000000000000000000000000000000000000000000006"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    )
+    .unwrap();
+    let prehash = Sha256::digest(b"sample");
+
+    let recovery_ids: Vec<_> = VerifyingKey::recover_all_from_prehash(&prehash, &signature)
+        .map(|(recovery_id, _)| recovery_id.to_byte())
+        .collect();
+    assert_eq!(recovery_ids, [0, 1, 2, 3]);
+}
+
+#[test]
+fn recover_all_from_prehash_invalid() {
+    // Neither 1 nor 1 + n is the x-coordinate of a point on the curve
+    let signature = Signature::from_scalars(
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    )
+    .unwrap();
+    let prehash = Sha256::digest(b"sample");
+    assert_eq!(
+        VerifyingKey::recover_all_from_prehash(&prehash, &signature).count(),
+        0
+    );
+
+    // Prehash shorter than half the field size
+    let signing_key = SigningKey::from_slice(&SECRET_KEY).unwrap();
+    let (signature, _) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+    assert_eq!(
+        VerifyingKey::recover_all_from_prehash(&prehash[..8], &signature).count(),
+        0
+    );
+    assert!(RecoveryId::trial_recovery_from_prehash(
+        signing_key.verifying_key(),
+        &prehash[..8],
+        &signature
+    )
+    .is_err());
+}
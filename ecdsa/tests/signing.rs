@@ -0,0 +1,112 @@
+//! Signing tests which use the NIST P-256 curve
+
+#![cfg(all(feature = "signing", feature = "verifying"))]
+
+use ecdsa::elliptic_curve::rand_core::{self, CryptoRng, RngCore};
+use hex_literal::hex;
+use p256::NistP256;
+use sha2::{Digest, Sha256, Sha512};
+use signature::{hazmat::PrehashVerifier, DigestSigner};
+
+type Signature = ecdsa::Signature<NistP256>;
+type SigningKey = ecdsa::SigningKey<NistP256>;
+
+/// Private key of the P-256 example from RFC 6979 Appendix A.2.5.
+const SECRET_KEY: [u8; 32] =
+    hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+/// Signature over `"sample"` with SHA-512 from RFC 6979 Appendix A.2.5.
+const SIGNATURE_SHA512: [u8; 64] = hex!(
+    "8496a60b5e9b47c825488827e0495b0e3fa109ec4568fd3f8d1097678eb97f00"
+    "2362ab1adbe2b8adf9cb9edab740ea6049c028114f2460f96554f61fae3302fe"
+);
+
+/// Hedged signature over the SHA-256 digest of `"sample"` with
+/// `Z = 0x01 * 32`, computed with an independent Python implementation of
+/// § 4 of draft-irtf-cfrg-det-sigs-with-noise.
+const SIGNATURE: [u8; 64] = hex!(
+    "6f198e5957919d3e8080ee9316672e4af98e7401cd447646ac19c4e17b21d4d9"
+    "4fe7d4b5721dbe40e16b214d0fc33761ac1fb00ba8853b69db937853b813a375"
+);
+
+/// RNG which always outputs the same byte, so that the random string `Z` is
+/// fixed.
+struct ConstantRng(u8);
+
+impl RngCore for ConstantRng {
+    fn next_u32(&mut self) -> u32 {
+        u32::from_ne_bytes([self.0; 4])
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        u64::from_ne_bytes([self.0; 8])
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(self.0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ConstantRng {}
+
+#[test]
+fn sign_prehash_hedged() {
+    let signing_key = SigningKey::from_slice(&SECRET_KEY).unwrap();
+    let prehash = Sha256::digest(b"sample");
+
+    let signature = signing_key
+        .sign_prehash_hedged::<Sha256>(&mut ConstantRng(0x01), &prehash)
+        .unwrap();
+    assert_eq!(signature, Signature::from_slice(&SIGNATURE).unwrap());
+    assert!(signing_key
+        .verifying_key()
+        .verify_prehash(&prehash, &signature)
+        .is_ok());
+
+    let digest_signature = signing_key
+        .try_sign_digest_hedged(&mut ConstantRng(0x01), Sha256::new_with_prefix(b"sample"))
+        .unwrap();
+    assert_eq!(digest_signature, signature);
+
+    // Fresh randomness changes the signature, which still verifies
+    let other = signing_key
+        .sign_prehash_hedged::<Sha256>(&mut ConstantRng(0x02), &prehash)
+        .unwrap();
+    assert_ne!(other, signature);
+    assert_ne!(
+        other,
+        signing_key
+            .sign_prehash_rfc6979::<Sha256>(&prehash)
+            .unwrap()
+    );
+    assert!(signing_key
+        .verifying_key()
+        .verify_prehash(&prehash, &other)
+        .is_ok());
+}
+
+#[test]
+fn sign_digest_rfc6979() {
+    let signing_key = SigningKey::from_slice(&SECRET_KEY).unwrap();
+    let expected = Signature::from_slice(&SIGNATURE_SHA512).unwrap();
+
+    // `k` is derived using the digest the message was hashed with
+    let signature: Signature = signing_key.sign_digest(Sha512::new_with_prefix(b"sample"));
+    assert_eq!(signature, expected);
+    assert_eq!(
+        signing_key
+            .try_sign_digest_rfc6979::<Sha512>(Sha512::new_with_prefix(b"sample"))
+            .unwrap(),
+        expected
+    );
+
+    let signature: ecdsa::SignatureWithOid<NistP256> =
+        signing_key.sign_digest(Sha512::new_with_prefix(b"sample"));
+    assert_eq!(*signature.signature(), expected);
+    assert_eq!(signature.oid(), ecdsa::ECDSA_SHA512_OID);
+}
@@ -0,0 +1,300 @@
+//! CBOR Object Signing and Encryption (COSE) support.
+//!
+//! This module provides:
+//!
+//! - [`COSE_Key`][RFC9052 Section 7] import and export for [`PublicKeyBytes`],
+//!   using the `OKP` key type and the `Ed25519` curve
+//! - signing and verification of [`COSE_Sign1`][RFC9052 Section 4.2]
+//!   structures with the `EdDSA` algorithm, generic over any
+//!   [`Signer`]/[`Verifier`] of [`Signature`]s
+//!
+//! Apart from the curve, this module is kept identical to the `cose` module
+//! of the `ed448-signature` crate, so fixes have to be applied to both.
+//!
+//! [RFC9052 Section 4.2]: https://www.rfc-editor.org/rfc/rfc9052#section-4.2
+//! [RFC9052 Section 7]: https://www.rfc-editor.org/rfc/rfc9052#section-7
+
+use crate::{Error, PublicKeyBytes, Signature};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use minicbor::{
+    data::{Tag, Type},
+    decode, encode, Decoder, Encoder,
+};
+use signature::{Signer, Verifier};
+
+/// COSE Algorithms registry value of `EdDSA`, as registered in
+/// [RFC 9053 Section 2.2](https://www.rfc-editor.org/rfc/rfc9053#section-2.2).
+pub const ALGORITHM: i64 = -8;
+
+/// COSE Elliptic Curves registry value of `Ed25519`, as registered in
+/// [RFC 9053 Section 7.1](https://www.rfc-editor.org/rfc/rfc9053#section-7.1).
+pub const CURVE: i64 = 6;
+
+/// `COSE_Key` label of the key type.
+const KEY_KTY: i64 = 1;
+
+/// `COSE_Key` label of the curve.
+const KEY_CRV: i64 = -1;
+
+/// `COSE_Key` label of the public key.
+const KEY_X: i64 = -2;
+
+/// `OKP` key type.
+const KTY_OKP: i64 = 1;
+
+/// Header label of the algorithm.
+const HEADER_ALG: i64 = 1;
+
+/// Header label of the critical headers.
+const HEADER_CRIT: i64 = 2;
+
+/// CBOR tag of a `COSE_Sign1` structure.
+const COSE_SIGN1_TAG: u64 = 18;
+
+/// Context string of the `Sig_structure` of a `COSE_Sign1` structure.
+const SIGNATURE1_CONTEXT: &str = "Signature1";
+
+impl PublicKeyBytes {
+    /// Parse [`PublicKeyBytes`] from a CBOR-encoded `OKP` `COSE_Key`.
+    ///
+    /// Parameters other than `kty`, `crv`, and `x` are ignored.
+    pub fn from_cose_key(bytes: &[u8]) -> signature::Result<Self> {
+        decode_key(bytes).map_err(|_| Error::new())
+    }
+
+    /// Serialize these [`PublicKeyBytes`] as a CBOR-encoded `OKP` `COSE_Key`.
+    pub fn to_cose_key(&self) -> signature::Result<Vec<u8>> {
+        encode_key(&self.0).map_err(|_| Error::new())
+    }
+}
+
+/// Sign the given payload and externally supplied data using the given
+/// signer, returning a tagged, CBOR-encoded `COSE_Sign1` structure.
+///
+/// The protected header only contains the `EdDSA` [`ALGORITHM`], and the
+/// unprotected header is empty.
+pub fn sign_cose_sign1<S>(
+    signer: &S,
+    payload: &[u8],
+    external_aad: &[u8],
+) -> signature::Result<Vec<u8>>
+where
+    S: Signer<Signature> + ?Sized,
+{
+    let protected = encode_protected(ALGORITHM).map_err(|_| Error::new())?;
+    let to_be_signed =
+        encode_to_be_signed(&protected, external_aad, payload).map_err(|_| Error::new())?;
+    let signature = signer.try_sign(&to_be_signed)?;
+
+    encode_sign1(&protected, payload, &signature.to_bytes()).map_err(|_| Error::new())
+}
+
+/// Verify a CBOR-encoded `COSE_Sign1` structure with the given externally
+/// supplied data using the given verifier, returning its payload.
+///
+/// The structure may optionally be tagged. Its protected header must specify
+/// the `EdDSA` [`ALGORITHM`], and must not contain any critical headers.
+/// Detached payloads aren't supported.
+pub fn verify_cose_sign1<V>(
+    verifier: &V,
+    cose_sign1: &[u8],
+    external_aad: &[u8],
+) -> signature::Result<Vec<u8>>
+where
+    V: Verifier<Signature> + ?Sized,
+{
+    let sign1 = decode_sign1(cose_sign1).map_err(|_| Error::new())?;
+    if decode_protected_alg(sign1.protected).map_err(|_| Error::new())? != ALGORITHM {
+        return Err(Error::new());
+    }
+
+    let signature = Signature::from_slice(sign1.signature)?;
+    let to_be_signed = encode_to_be_signed(sign1.protected, external_aad, sign1.payload)
+        .map_err(|_| Error::new())?;
+    verifier.verify(&to_be_signed, &signature)?;
+
+    Ok(sign1.payload.to_vec())
+}
+
+/// Fields of a `COSE_Sign1` structure.
+struct Sign1<'a> {
+    /// Serialized protected header.
+    protected: &'a [u8],
+
+    /// Attached payload.
+    payload: &'a [u8],
+
+    /// Signature over the `Sig_structure`.
+    signature: &'a [u8],
+}
+
+/// Decode the public key of an `OKP` `COSE_Key`.
+fn decode_key(bytes: &[u8]) -> Result<PublicKeyBytes, decode::Error> {
+    let mut decoder = Decoder::new(bytes);
+    let (mut kty, mut crv, mut x) = (None, None, None);
+
+    let entries = decoder
+        .map()?
+        .ok_or_else(|| decode::Error::message("indefinite length map"))?;
+    for _ in 0..entries {
+        match decode_label(&mut decoder)? {
+            Some(KEY_KTY) => set_once(&mut kty, decoder.i64()?)?,
+            Some(KEY_CRV) => set_once(&mut crv, decoder.i64()?)?,
+            Some(KEY_X) => set_once(&mut x, decoder.bytes()?)?,
+            _ => decoder.skip()?,
+        }
+    }
+
+    if decoder.position() != bytes.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    if kty != Some(KTY_OKP) {
+        return Err(decode::Error::message("unsupported key type"));
+    }
+
+    if crv != Some(CURVE) {
+        return Err(decode::Error::message("unsupported curve"));
+    }
+
+    x.ok_or_else(|| decode::Error::message("missing public key"))?
+        .try_into()
+        .map(PublicKeyBytes)
+        .map_err(|_| decode::Error::message("invalid public key"))
+}
+
+/// Encode an `OKP` `COSE_Key` for the given public key.
+fn encode_key(x: &[u8]) -> Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .map(3)?
+        .i64(KEY_KTY)?
+        .i64(KTY_OKP)?
+        .i64(KEY_CRV)?
+        .i64(CURVE)?
+        .i64(KEY_X)?
+        .bytes(x)?;
+    Ok(encoder.into_writer())
+}
+
+/// Decode a `COSE_Sign1` structure, which may optionally be tagged.
+fn decode_sign1(bytes: &[u8]) -> Result<Sign1<'_>, decode::Error> {
+    let mut decoder = Decoder::new(bytes);
+
+    if decoder.datatype()? == Type::Tag && decoder.tag()? != Tag::Unassigned(COSE_SIGN1_TAG) {
+        return Err(decode::Error::message("unexpected tag"));
+    }
+
+    if decoder.array()? != Some(4) {
+        return Err(decode::Error::message("expected array of 4 elements"));
+    }
+
+    let protected = decoder.bytes()?;
+    if decoder.datatype()? != Type::Map {
+        return Err(decode::Error::message("expected unprotected header map"));
+    }
+    decoder.skip()?;
+    let payload = decoder.bytes()?;
+    let signature = decoder.bytes()?;
+
+    if decoder.position() != bytes.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    Ok(Sign1 {
+        protected,
+        payload,
+        signature,
+    })
+}
+
+/// Encode a tagged `COSE_Sign1` structure with an empty unprotected header.
+fn encode_sign1(
+    protected: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .tag(Tag::Unassigned(COSE_SIGN1_TAG))?
+        .array(4)?
+        .bytes(protected)?
+        .map(0)?
+        .bytes(payload)?
+        .bytes(signature)?;
+    Ok(encoder.into_writer())
+}
+
+/// Decode the algorithm from a serialized protected header, rejecting any
+/// critical headers.
+fn decode_protected_alg(protected: &[u8]) -> Result<i64, decode::Error> {
+    let mut decoder = Decoder::new(protected);
+    let mut alg = None;
+
+    let entries = decoder
+        .map()?
+        .ok_or_else(|| decode::Error::message("indefinite length map"))?;
+    for _ in 0..entries {
+        match decode_label(&mut decoder)? {
+            Some(HEADER_ALG) => set_once(&mut alg, decoder.i64()?)?,
+            Some(HEADER_CRIT) => return Err(decode::Error::message("unsupported critical header")),
+            _ => decoder.skip()?,
+        }
+    }
+
+    if decoder.position() != protected.len() {
+        return Err(decode::Error::message("trailing data"));
+    }
+
+    alg.ok_or_else(|| decode::Error::message("missing algorithm"))
+}
+
+/// Encode a protected header containing only the given algorithm.
+fn encode_protected(alg: i64) -> Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder.map(1)?.i64(HEADER_ALG)?.i64(alg)?;
+    Ok(encoder.into_writer())
+}
+
+/// Encode the `Sig_structure` of a `COSE_Sign1` structure, i.e. the data
+/// which is actually signed.
+fn encode_to_be_signed(
+    protected: &[u8],
+    external_aad: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, encode::Error<Infallible>> {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder
+        .array(4)?
+        .str(SIGNATURE1_CONTEXT)?
+        .bytes(protected)?
+        .bytes(external_aad)?
+        .bytes(payload)?;
+    Ok(encoder.into_writer())
+}
+
+/// Decode a map label, returning `None` (after skipping it) if it isn't an
+/// integer.
+fn decode_label(decoder: &mut Decoder<'_>) -> Result<Option<i64>, decode::Error> {
+    match decoder.datatype()? {
+        Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::Int => decoder.i64().map(Some),
+        _ => decoder.skip().map(|_| None),
+    }
+}
+
+/// Set a map value, rejecting duplicate labels.
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Result<(), decode::Error> {
+    match slot.replace(value) {
+        Some(_) => Err(decode::Error::message("duplicate label")),
+        None => Ok(()),
+    }
+}
@@ -242,6 +242,10 @@
 //!
 //! - `pkcs8`: support for decoding/encoding PKCS#8-formatted private keys using the
 //!   [`KeypairBytes`] type.
+//! - `cose`: support for `COSE_Key` and `COSE_Sign1` structures using the [`PublicKeyBytes`]
+//!   type and any [`signature::Signer`]/[`signature::Verifier`] of [`Signature`]s.
+//! - `encryption`: support for decoding/encoding password-encrypted (PBES2) PKCS#8 private keys,
+//!   e.g. `ENCRYPTED PRIVATE KEY` PEM documents, using the [`KeypairBytes`] type.
 //! - `std` *(default)*: Enable `std` support in [`signature`], which currently only affects whether
 //!   [`signature::Error`] implements `std::error::Error`.
 //! - `serde`: Implement `serde::Deserialize` and `serde::Serialize` for [`Signature`]. Signatures
@@ -262,6 +266,9 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "cose")]
+pub mod cose;
+
 mod hex;
 
 #[cfg(feature = "pkcs8")]
@@ -0,0 +1,116 @@
+//! COSE tests
+
+#![cfg(feature = "cose")]
+
+use ed25519::{
+    cose::{sign_cose_sign1, verify_cose_sign1},
+    signature::{self, Signer, Verifier},
+    PublicKeyBytes, Signature,
+};
+use hex_literal::hex;
+
+/// Secret key of test 1 from RFC 8032 § 7.1.
+const SECRET_KEY: [u8; 32] =
+    hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+
+/// Public key of test 1 from RFC 8032 § 7.1 as an `OKP` `COSE_Key`.
+const COSE_KEY: [u8; 40] =
+    hex!("a301012006215820d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+
+/// `Sig_structure` of the RFC 9052 Appendix C.2.1 payload with the `EdDSA`
+/// algorithm.
+const TO_BE_SIGNED: [u8; 38] =
+    hex!("846a5369676e61747572653143a101274054546869732069732074686520636f6e74656e742e");
+
+/// Signs and verifies using `ed25519-dalek`, which uses its own copy of the
+/// `ed25519::Signature` type.
+struct DalekKey(ed25519_dalek::SigningKey);
+
+impl Signer<Signature> for DalekKey {
+    fn try_sign(&self, msg: &[u8]) -> signature::Result<Signature> {
+        Ok(Signature::from_bytes(&self.0.sign(msg).to_bytes()))
+    }
+}
+
+impl Verifier<Signature> for DalekKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> signature::Result<()> {
+        let signature = ed25519_dalek::Signature::from_bytes(&signature.to_bytes());
+        self.0.verify(msg, &signature)
+    }
+}
+
+#[test]
+fn cose_key_roundtrip() {
+    let public_key = PublicKeyBytes::from_cose_key(&COSE_KEY).unwrap();
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&SECRET_KEY);
+    assert_eq!(public_key.0, signing_key.verifying_key().to_bytes());
+    assert_eq!(public_key.to_cose_key().unwrap(), COSE_KEY);
+}
+
+#[test]
+fn cose_key_invalid() {
+    // EC2 key type
+    let mut key = COSE_KEY;
+    key[2] = 0x02;
+    assert!(PublicKeyBytes::from_cose_key(&key).is_err());
+
+    // Ed448 curve
+    let mut key = COSE_KEY;
+    key[4] = 0x07;
+    assert!(PublicKeyBytes::from_cose_key(&key).is_err());
+
+    // Truncated public key
+    let mut key = COSE_KEY.to_vec();
+    key[6] = 0x58;
+    key[7] = 0x1f;
+    key.pop();
+    assert!(PublicKeyBytes::from_cose_key(&key).is_err());
+}
+
+#[test]
+fn sign_verify_cose_sign1() {
+    let key = DalekKey(ed25519_dalek::SigningKey::from_bytes(&SECRET_KEY));
+    let payload = b"This is the content.";
+
+    let cose_sign1 = sign_cose_sign1(&key, payload, b"").unwrap();
+    let signature = key.sign(&TO_BE_SIGNED);
+    assert_eq!(
+        cose_sign1[..30],
+        hex!("d28443a10127a054546869732069732074686520636f6e74656e742e5840")
+    );
+    assert_eq!(cose_sign1[30..], signature.to_bytes());
+
+    assert_eq!(verify_cose_sign1(&key, &cose_sign1, b"").unwrap(), payload);
+    assert!(verify_cose_sign1(&key, &cose_sign1, b"aad").is_err());
+
+    let cose_sign1 = sign_cose_sign1(&key, payload, b"aad").unwrap();
+    assert_eq!(
+        verify_cose_sign1(&key, &cose_sign1, b"aad").unwrap(),
+        payload
+    );
+    assert!(verify_cose_sign1(&key, &cose_sign1, b"").is_err());
+}
+
+#[test]
+fn verify_cose_sign1_invalid() {
+    let key = DalekKey(ed25519_dalek::SigningKey::from_bytes(&SECRET_KEY));
+    let cose_sign1 = sign_cose_sign1(&key, b"This is the content.", b"").unwrap();
+
+    // Untagged
+    assert!(verify_cose_sign1(&key, &cose_sign1[1..], b"").is_ok());
+
+    // ES256 algorithm
+    let mut invalid = cose_sign1.clone();
+    invalid[5] = 0x26;
+    assert!(verify_cose_sign1(&key, &invalid, b"").is_err());
+
+    // Modified payload
+    let mut invalid = cose_sign1.clone();
+    invalid[10] ^= 1;
+    assert!(verify_cose_sign1(&key, &invalid, b"").is_err());
+
+    // Modified signature
+    let mut invalid = cose_sign1;
+    invalid[40] ^= 1;
+    assert!(verify_cose_sign1(&key, &invalid, b"").is_err());
+}
@@ -3,6 +3,12 @@
 #![allow(non_snake_case)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "cose")]
+pub mod cose;
+
 mod hex;
 
 #[cfg(feature = "pkcs8")]
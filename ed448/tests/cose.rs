@@ -0,0 +1,126 @@
+//! COSE framing tests
+//!
+//! This crate doesn't implement Ed448, so these tests use a precomputed
+//! signature and only check the `COSE_Key` and `COSE_Sign1` encodings, not
+//! the signatures themselves.
+
+#![cfg(feature = "cose")]
+
+use core::cell::RefCell;
+use ed448_signature::{
+    cose::{sign_cose_sign1, verify_cose_sign1},
+    pkcs8::PublicKeyBytes,
+    signature::{self, Signer, Verifier},
+    Error, Signature,
+};
+use hex_literal::hex;
+
+/// Public key of test 1 from RFC 8032 § 7.4 as an `OKP` `COSE_Key`.
+const COSE_KEY: [u8; 65] = hex!(
+    "a301012007215839"
+    "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778"
+    "edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180"
+);
+
+/// Ed448 signature over [`TO_BE_SIGNED`] by the secret key of test 1 from
+/// RFC 8032 § 7.4, computed with OpenSSL 3.5.
+const SIGNATURE: [u8; Signature::BYTE_SIZE] = hex!(
+    "988240a3a2f189bd486de14aa77f54686c576a09f2e7ed9bae910df9139c2ac3"
+    "be7c27b7e10a20fa17c9d57d3510a2cf1f634bc0345ab9be00849842171d1e9e"
+    "98b2674c0e38bfcf6c557a1692b01b71015a47ac9f7748840cad1da80cbb5b34"
+    "9309febb912672b377c8b2072af1598b3700"
+);
+
+/// `Sig_structure` of the RFC 9052 Appendix C.2.1 payload with the `EdDSA`
+/// algorithm.
+const TO_BE_SIGNED: [u8; 38] =
+    hex!("846a5369676e61747572653143a101274054546869732069732074686520636f6e74656e742e");
+
+/// Known-answer signer/verifier which records the signed message, always
+/// returns [`SIGNATURE`], and only accepts [`SIGNATURE`] over
+/// [`TO_BE_SIGNED`].
+#[derive(Default)]
+struct KnownAnswerKey {
+    signed: RefCell<Vec<u8>>,
+}
+
+impl Signer<Signature> for KnownAnswerKey {
+    fn try_sign(&self, msg: &[u8]) -> signature::Result<Signature> {
+        *self.signed.borrow_mut() = msg.to_vec();
+        Ok(Signature::from_bytes(&SIGNATURE))
+    }
+}
+
+impl Verifier<Signature> for KnownAnswerKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> signature::Result<()> {
+        if msg == TO_BE_SIGNED && signature.to_bytes() == SIGNATURE {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+#[test]
+fn cose_key_roundtrip() {
+    let public_key = PublicKeyBytes::from_cose_key(&COSE_KEY).unwrap();
+    assert_eq!(public_key.0, COSE_KEY[8..]);
+    assert_eq!(public_key.to_cose_key().unwrap(), COSE_KEY);
+}
+
+#[test]
+fn cose_key_invalid() {
+    // EC2 key type
+    let mut key = COSE_KEY;
+    key[2] = 0x02;
+    assert!(PublicKeyBytes::from_cose_key(&key).is_err());
+
+    // Ed25519 curve
+    let mut key = COSE_KEY;
+    key[4] = 0x06;
+    assert!(PublicKeyBytes::from_cose_key(&key).is_err());
+
+    // Missing public key
+    assert!(PublicKeyBytes::from_cose_key(&hex!("a201012007")).is_err());
+}
+
+#[test]
+fn sign_cose_sign1_structure() {
+    let key = KnownAnswerKey::default();
+    let cose_sign1 = sign_cose_sign1(&key, b"This is the content.", b"").unwrap();
+
+    assert_eq!(*key.signed.borrow(), TO_BE_SIGNED);
+    assert_eq!(
+        cose_sign1[..30],
+        hex!("d28443a10127a054546869732069732074686520636f6e74656e742e5872")
+    );
+    assert_eq!(cose_sign1[30..], SIGNATURE);
+}
+
+#[test]
+fn verify_cose_sign1_structure() {
+    let key = KnownAnswerKey::default();
+    let cose_sign1 = sign_cose_sign1(&key, b"This is the content.", b"").unwrap();
+
+    assert_eq!(
+        verify_cose_sign1(&key, &cose_sign1, b"").unwrap(),
+        b"This is the content."
+    );
+
+    // Untagged
+    assert!(verify_cose_sign1(&key, &cose_sign1[1..], b"").is_ok());
+
+    // Mismatched external data
+    assert!(verify_cose_sign1(&key, &cose_sign1, b"aad").is_err());
+
+    // ES256 algorithm
+    let mut invalid = cose_sign1.clone();
+    invalid[5] = 0x26;
+    assert!(verify_cose_sign1(&key, &invalid, b"").is_err());
+
+    // Truncated signature
+    let mut invalid = cose_sign1;
+    invalid[29] = 0x71;
+    invalid.pop();
+    assert!(verify_cose_sign1(&key, &invalid, b"").is_err());
+}
@@ -1,4 +1,4 @@
-//! Constant-time comparison helpers for [`ByteArray`].
+//! Constant-time comparison and arithmetic helpers for [`ByteArray`].
 
 use crate::{ArrayLength, ByteArray};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
@@ -30,6 +30,38 @@ pub(crate) fn ct_lt<N: ArrayLength<u8>>(a: &ByteArray<N>, b: &ByteArray<N>) -> C
     !borrow.ct_eq(&0)
 }
 
+/// Constant-time subtraction, wrapping on underflow.
+///
+/// Inputs are interpreted as big endian integers.
+pub(crate) fn ct_sub<N: ArrayLength<u8>>(a: &ByteArray<N>, b: &ByteArray<N>) -> ByteArray<N> {
+    let mut ret = ByteArray::<N>::default();
+    let mut borrow = 0;
+
+    for ((r, &a), &b) in ret.iter_mut().zip(a.iter()).zip(b.iter()).rev() {
+        let c = (b as u16).wrapping_add(borrow >> (u8::BITS - 1));
+        let d = (a as u16).wrapping_sub(c);
+        *r = d as u8;
+        borrow = d >> u8::BITS as u8;
+    }
+
+    ret
+}
+
+/// Constant-time selection: returns `a` if `choice` is 0, or `b` if it is 1.
+pub(crate) fn ct_select<N: ArrayLength<u8>>(
+    a: &ByteArray<N>,
+    b: &ByteArray<N>,
+    choice: Choice,
+) -> ByteArray<N> {
+    let mut ret = ByteArray::<N>::default();
+
+    for ((r, a), b) in ret.iter_mut().zip(a.iter()).zip(b.iter()) {
+        *r = u8::conditional_select(a, b, choice);
+    }
+
+    ret
+}
+
 #[cfg(test)]
 mod tests {
     const A: [u8; 4] = [0, 0, 0, 0];
@@ -84,4 +116,35 @@ mod tests {
         assert_eq!(ct_lt(&E.into(), &F.into()).unwrap_u8(), 1);
         assert_eq!(ct_lt(&F.into(), &E.into()).unwrap_u8(), 0);
     }
+
+    #[test]
+    fn ct_sub() {
+        use super::ct_sub;
+
+        assert_eq!(ct_sub(&A.into(), &A.into()).as_slice(), &A);
+        assert_eq!(ct_sub(&B.into(), &A.into()).as_slice(), &B);
+        assert_eq!(ct_sub(&B.into(), &B.into()).as_slice(), &A);
+        assert_eq!(ct_sub(&D.into(), &B.into()).as_slice(), &C);
+        assert_eq!(ct_sub(&F.into(), &B.into()).as_slice(), &E);
+        assert_eq!(ct_sub(&A.into(), &B.into()).as_slice(), &F);
+        assert_eq!(
+            ct_sub(&C.into(), &B.into()).as_slice(),
+            &[0xFE, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn ct_select() {
+        use super::ct_select;
+        use subtle::Choice;
+
+        assert_eq!(
+            ct_select(&A.into(), &F.into(), Choice::from(0)).as_slice(),
+            &A
+        );
+        assert_eq!(
+            ct_select(&A.into(), &F.into(), Choice::from(1)).as_slice(),
+            &F
+        );
+    }
 }
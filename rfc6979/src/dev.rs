@@ -0,0 +1,211 @@
+//! Development-related functionality.
+//!
+//! Parser and runner for the `HMAC_DRBG.rsp` response files from the NIST
+//! Cryptographic Algorithm Validation Program (CAVP) DRBG test vectors:
+//!
+//! <https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/random-number-generators>
+
+use crate::HmacDrbg;
+use alloc::{string::String, vec, vec::Vec};
+use hmac::digest::{core_api::BlockSizeUser, Digest, FixedOutputReset};
+
+/// Group of CAVP test vectors sharing the same section header, e.g.
+/// `[SHA-256]` followed by `[PredictionResistance = True]`.
+pub struct TestGroup {
+    /// Name of the digest, e.g. `SHA-256`.
+    pub digest: String,
+
+    /// Whether the test vectors use prediction resistance.
+    pub prediction_resistance: bool,
+
+    /// Test vectors in this group.
+    pub vectors: Vec<TestVector>,
+}
+
+/// CAVP `HMAC_DRBG` test vector.
+#[derive(Default)]
+pub struct TestVector {
+    /// Test vector number (`COUNT`).
+    pub count: usize,
+
+    /// Entropy input used to instantiate the DRBG (`EntropyInput`).
+    pub entropy_input: Vec<u8>,
+
+    /// Nonce used to instantiate the DRBG (`Nonce`).
+    pub nonce: Vec<u8>,
+
+    /// Personalization string (`PersonalizationString`).
+    pub personalization_string: Vec<u8>,
+
+    /// Entropy input for an explicit reseed (`EntropyInputReseed`).
+    pub entropy_input_reseed: Option<Vec<u8>>,
+
+    /// Additional input for an explicit reseed (`AdditionalInputReseed`).
+    pub additional_input_reseed: Vec<u8>,
+
+    /// Additional input for each generate call (`AdditionalInput`).
+    pub additional_input: Vec<Vec<u8>>,
+
+    /// Entropy input for each generate call with prediction resistance
+    /// (`EntropyInputPR`).
+    pub entropy_input_pr: Vec<Vec<u8>>,
+
+    /// Expected output of the final generate call (`ReturnedBits`).
+    pub returned_bits: Vec<u8>,
+}
+
+impl TestVector {
+    /// Run this test vector against [`HmacDrbg`], returning the output of the
+    /// final generate call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any `HMAC_DRBG` operation returns an error.
+    pub fn run<D>(&self, prediction_resistance: bool) -> Vec<u8>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        let security_strength = core::cmp::min(self.entropy_input.len() * 8, 256);
+        let mut drbg = HmacDrbg::<D>::instantiate(
+            security_strength,
+            &self.entropy_input,
+            &self.nonce,
+            &self.personalization_string,
+        )
+        .expect("instantiate error");
+
+        if let Some(entropy_input_reseed) = &self.entropy_input_reseed {
+            drbg.reseed(entropy_input_reseed, &self.additional_input_reseed)
+                .expect("reseed error");
+        }
+
+        let mut out = vec![0u8; self.returned_bits.len()];
+
+        for (i, additional_input) in self.additional_input.iter().enumerate() {
+            if prediction_resistance {
+                drbg.generate_with_prediction_resistance(
+                    &mut out,
+                    &self.entropy_input_pr[i],
+                    additional_input,
+                )
+            } else {
+                drbg.generate(&mut out, additional_input)
+            }
+            .expect("generate error");
+        }
+
+        out
+    }
+}
+
+/// Parse the contents of a CAVP `HMAC_DRBG.rsp` file.
+///
+/// # Panics
+///
+/// Panics if the input is malformed.
+pub fn parse(rsp: &str) -> Vec<TestGroup> {
+    let mut groups: Vec<TestGroup> = Vec::new();
+
+    for line in rsp.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            match header.split_once('=') {
+                None => groups.push(TestGroup {
+                    digest: header.into(),
+                    prediction_resistance: false,
+                    vectors: Vec::new(),
+                }),
+                Some((key, value)) if key.trim() == "PredictionResistance" => {
+                    let group = groups.last_mut().expect("missing digest header");
+                    group.prediction_resistance = value.trim() == "True";
+                }
+                // Other headers give field lengths which are implied by the fields
+                Some(_) => (),
+            }
+
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').expect("malformed line");
+        let (key, value) = (key.trim(), value.trim());
+        let vectors = &mut groups.last_mut().expect("missing digest header").vectors;
+
+        if key == "COUNT" {
+            vectors.push(TestVector {
+                count: value.parse().expect("malformed COUNT"),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let vector = vectors.last_mut().expect("missing COUNT");
+        let value = decode_hex(value);
+
+        match key {
+            "EntropyInput" => vector.entropy_input = value,
+            "Nonce" => vector.nonce = value,
+            "PersonalizationString" => vector.personalization_string = value,
+            "EntropyInputReseed" => vector.entropy_input_reseed = Some(value),
+            "AdditionalInputReseed" => vector.additional_input_reseed = value,
+            "AdditionalInput" => vector.additional_input.push(value),
+            "EntropyInputPR" => vector.entropy_input_pr.push(value),
+            "ReturnedBits" => vector.returned_bits = value,
+            _ => panic!("unknown field: {}", key),
+        }
+    }
+
+    groups
+}
+
+/// Parse and run all test vectors in the contents of a CAVP `HMAC_DRBG.rsp`
+/// file, returning the number of test vectors run.
+///
+/// Groups using digests other than SHA-1 and the SHA-2 family are skipped.
+///
+/// # Panics
+///
+/// Panics if the input is malformed, or if any test vector fails.
+pub fn run(rsp: &str) -> usize {
+    let mut n = 0;
+
+    for group in parse(rsp) {
+        let run: fn(&TestVector, bool) -> Vec<u8> = match group.digest.as_str() {
+            "SHA-1" => TestVector::run::<sha1::Sha1>,
+            "SHA-224" => TestVector::run::<sha2::Sha224>,
+            "SHA-256" => TestVector::run::<sha2::Sha256>,
+            "SHA-384" => TestVector::run::<sha2::Sha384>,
+            "SHA-512" => TestVector::run::<sha2::Sha512>,
+            "SHA-512/224" => TestVector::run::<sha2::Sha512_224>,
+            "SHA-512/256" => TestVector::run::<sha2::Sha512_256>,
+            _ => continue,
+        };
+
+        for vector in &group.vectors {
+            assert_eq!(
+                run(vector, group.prediction_resistance),
+                vector.returned_bits,
+                "[{}] [PredictionResistance = {}] COUNT = {}",
+                group.digest,
+                group.prediction_resistance,
+                vector.count
+            );
+
+            n += 1;
+        }
+    }
+
+    n
+}
+
+/// Decode a hexadecimal string.
+fn decode_hex(hex: &str) -> Vec<u8> {
+    assert!(hex.len() % 2 == 0, "odd number of hex characters");
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..(i + 2)], 16).expect("invalid hex"))
+        .collect()
+}
@@ -0,0 +1,46 @@
+//! Error types.
+
+use core::fmt;
+
+/// Result type with the `rfc6979` crate's [`Error`] type.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// `HMAC_DRBG` errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Requested security strength is not supported by the digest.
+    SecurityStrength,
+
+    /// Entropy input is shorter than the security strength.
+    InsufficientEntropy,
+
+    /// Nonce is shorter than half the security strength.
+    InsufficientNonce,
+
+    /// Input exceeds the maximum length permitted by NIST SP800-90A.
+    InputTooLong,
+
+    /// Requested output exceeds `max_number_of_bits_per_request`.
+    RequestTooLong,
+
+    /// Reseed interval is zero or exceeds the NIST SP800-90A limit.
+    ReseedInterval,
+
+    /// Reseed interval was reached: the DRBG must be reseeded.
+    ReseedRequired,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::SecurityStrength => "unsupported security strength",
+            Error::InsufficientEntropy => "insufficient entropy input",
+            Error::InsufficientNonce => "insufficient nonce",
+            Error::InputTooLong => "input too long",
+            Error::RequestTooLong => "requested output too long",
+            Error::ReseedInterval => "invalid reseed interval",
+            Error::ReseedRequired => "reseed required",
+        })
+    }
+}
@@ -0,0 +1,374 @@
+//! `HMAC_DRBG` as described in NIST SP800-90A.
+
+use crate::{Error, Result};
+use hmac::{
+    digest::{
+        core_api::{Block, BlockSizeUser},
+        generic_array::typenum::Unsigned,
+        Digest, FixedOutputReset, Output,
+    },
+    Mac, SimpleHmac,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "rand_core")]
+use {
+    core::num::NonZeroU32,
+    rand_core::{CryptoRng, RngCore},
+};
+
+/// Maximum length of the entropy input, personalization string and
+/// additional input in bytes (2^35 bits).
+const MAX_LENGTH: u64 = 1 << 32;
+
+/// Maximum number of bytes per request (2^19 bits).
+const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+
+/// Maximum number of requests between reseeds.
+const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+
+/// Security strengths supported by NIST SP800-90A (in bits).
+const SECURITY_STRENGTHS: [usize; 4] = [112, 128, 192, 256];
+
+/// Implementation of `HMAC_DRBG` as described in NIST SP800-90A.
+///
+/// <https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final>
+///
+/// This is a HMAC-based deterministic random bit generator used compute a
+/// deterministic ephemeral scalar `k`, which can also be used as a general
+/// purpose DRBG via [`HmacDrbg::instantiate`], [`HmacDrbg::reseed`] and
+/// [`HmacDrbg::generate`].
+///
+/// The internal `K` and `V` state is zeroized on drop.
+pub struct HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    /// HMAC key `K` (see RFC 6979 Section 3.2.c)
+    k: Output<D>,
+
+    /// Chaining value `V` (see RFC 6979 Section 3.2.c)
+    v: Output<D>,
+
+    /// Number of requests since instantiation or the last reseed.
+    reseed_counter: u64,
+
+    /// Maximum number of requests between reseeds.
+    reseed_interval: u64,
+
+    /// Security strength of this instantiation in bits.
+    security_strength: usize,
+}
+
+impl<D> HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    /// Initialize `HMAC_DRBG`
+    ///
+    /// This does not check the lengths of its inputs, as RFC6979 uses the
+    /// secret key and message digest in place of the entropy input and nonce.
+    /// Use [`HmacDrbg::instantiate`] for general purpose use.
+    pub fn new(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        let mut drbg = Self::init();
+        drbg.update(&[entropy_input, nonce, personalization_string]);
+        drbg
+    }
+
+    /// Initialize `HMAC_DRBG` for hedged signatures, using the secret key
+    /// `x`, the reduced message digest `h` and the random string `z`.
+    ///
+    /// As described in [draft-irtf-cfrg-det-sigs-with-noise § 4], this
+    /// replaces steps d and f of [RFC6979 § 3.2] with:
+    ///
+    /// ```text
+    /// K = HMAC_K(V || 0x00 || Z || 000... || int2octets(x) || 000... || bits2octets(h1))
+    /// ```
+    ///
+    /// where each run of zero bytes pads `V || 0x00 || Z` and `int2octets(x)`
+    /// respectively to a multiple of the digest's block size, so that the
+    /// secret key never shares an HMAC compression function input with `Z`.
+    ///
+    /// [draft-irtf-cfrg-det-sigs-with-noise § 4]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-det-sigs-with-noise#section-4
+    /// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+    pub fn new_hedged(x: &[u8], h: &[u8], z: &[u8]) -> Self {
+        let mut drbg = Self::init();
+        let zeros = Block::<D>::default();
+        let z_padding = padding_len::<D>(drbg.v.len() + 1 + z.len());
+        let x_padding = padding_len::<D>(x.len());
+
+        drbg.update(&[z, &zeros[..z_padding], x, &zeros[..x_padding], h]);
+        drbg
+    }
+
+    /// Instantiate `HMAC_DRBG` as described in NIST SP800-90A § 10.1.2.3.
+    ///
+    /// Accepts the following parameters:
+    ///
+    /// - `security_strength`: requested security strength in bits
+    /// - `entropy_input`: at least `security_strength` bits of entropy
+    /// - `nonce`: at least `security_strength / 2` bits
+    /// - `personalization_string`: optional personalization string
+    ///
+    /// The requested security strength is rounded up to the next of 112, 128,
+    /// 192 or 256 bits, and must not exceed the highest strength supported by
+    /// the digest `D`.
+    pub fn instantiate(
+        security_strength: usize,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Result<Self> {
+        let security_strength = SECURITY_STRENGTHS
+            .iter()
+            .copied()
+            .find(|&strength| strength >= security_strength)
+            .filter(|&strength| strength <= Self::max_security_strength())
+            .ok_or(Error::SecurityStrength)?;
+
+        check_entropy_input(entropy_input, security_strength)?;
+        check_length(personalization_string)?;
+
+        if nonce.len() * 8 < security_strength / 2 {
+            return Err(Error::InsufficientNonce);
+        }
+
+        let mut drbg = Self::new(entropy_input, nonce, personalization_string);
+        drbg.security_strength = security_strength;
+        Ok(drbg)
+    }
+
+    /// Reseed `HMAC_DRBG` as described in NIST SP800-90A § 10.1.2.4.
+    ///
+    /// The entropy input must contain at least as many bits as the security
+    /// strength of this instantiation.
+    pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<()> {
+        check_entropy_input(entropy_input, self.security_strength)?;
+        check_length(additional_input)?;
+
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// Generate pseudorandom bits into the given byte slice as described in
+    /// NIST SP800-90A § 10.1.2.5.
+    ///
+    /// Returns [`Error::ReseedRequired`] once the reseed interval has been
+    /// reached, after which [`HmacDrbg::reseed`] must be called.
+    pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<()> {
+        if out.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLong);
+        }
+
+        check_length(additional_input)?;
+
+        if self.reseed_counter > self.reseed_interval {
+            return Err(Error::ReseedRequired);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+
+        self.generate_bytes(out, additional_input);
+        Ok(())
+    }
+
+    /// Generate pseudorandom bits with prediction resistance, i.e. reseed
+    /// using fresh entropy input prior to generating output, as described in
+    /// NIST SP800-90A § 9.3.1.
+    pub fn generate_with_prediction_resistance(
+        &mut self,
+        out: &mut [u8],
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        if out.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLong);
+        }
+
+        self.reseed(entropy_input, additional_input)?;
+        self.generate(out, &[])
+    }
+
+    /// Write the next `HMAC_DRBG` output to the given byte slice.
+    ///
+    /// This is the generation procedure described in RFC6979 § 3.2.h and
+    /// does not enforce the reseed interval or output length limits.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        self.generate_bytes(out, &[]);
+    }
+
+    /// Number of requests since instantiation or the last reseed.
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Set the maximum number of requests between reseeds.
+    ///
+    /// Must be nonzero and at most 2^48, the limit in NIST SP800-90A.
+    pub fn set_reseed_interval(&mut self, reseed_interval: u64) -> Result<()> {
+        if reseed_interval == 0 || reseed_interval > MAX_RESEED_INTERVAL {
+            return Err(Error::ReseedInterval);
+        }
+
+        self.reseed_interval = reseed_interval;
+        Ok(())
+    }
+
+    /// Security strength of this instantiation in bits.
+    pub fn security_strength(&self) -> usize {
+        self.security_strength
+    }
+
+    /// Highest security strength supported by the digest `D` in bits, as
+    /// given in NIST SP800-57 Part 1 § 5.6.1.2.
+    fn max_security_strength() -> usize {
+        match D::OutputSize::USIZE {
+            32.. => 256,
+            28.. => 192,
+            20.. => 128,
+            _ => 112,
+        }
+    }
+
+    /// Initial state prior to the first update, i.e. RFC6979 § 3.2 steps b-c.
+    fn init() -> Self {
+        let mut v = Output::<D>::default();
+        v.iter_mut().for_each(|b| *b = 0x01);
+
+        Self {
+            k: Output::<D>::default(),
+            v,
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+            security_strength: Self::max_security_strength(),
+        }
+    }
+
+    /// Generate output followed by a state update, without any checks.
+    fn generate_bytes(&mut self, out: &mut [u8], additional_input: &[u8]) {
+        let mut hmac = self.hmac();
+
+        for out_chunk in out.chunks_mut(self.v.len()) {
+            hmac.update(&self.v);
+            self.v = hmac.finalize_reset().into_bytes();
+            out_chunk.copy_from_slice(&self.v[..out_chunk.len()]);
+        }
+
+        self.update(&[additional_input]);
+        self.reseed_counter = self.reseed_counter.saturating_add(1);
+    }
+
+    /// `HMAC_DRBG_Update` as described in NIST SP800-90A § 10.1.2.2.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        for i in 0..=1 {
+            let mut hmac = self.hmac();
+            hmac.update(&self.v);
+            hmac.update(&[i]);
+
+            for data in provided_data {
+                hmac.update(data);
+            }
+
+            self.k = hmac.finalize().into_bytes();
+
+            let mut hmac = self.hmac();
+            hmac.update(&self.v);
+            self.v = hmac.finalize().into_bytes();
+
+            if provided_data.iter().all(|data| data.is_empty()) {
+                break;
+            }
+        }
+    }
+
+    /// Initialize HMAC keyed with `K`.
+    fn hmac(&self) -> SimpleHmac<D> {
+        SimpleHmac::new_from_slice(&self.k).expect("HMAC error")
+    }
+}
+
+impl<D> Drop for HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    fn drop(&mut self) {
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
+
+impl<D> ZeroizeOnDrop for HmacDrbg<D> where D: Digest + BlockSizeUser + FixedOutputReset {}
+
+/// Generate output using [`HmacDrbg::generate`] without additional input.
+///
+/// Requests longer than `max_number_of_bits_per_request` are split into
+/// multiple requests.
+///
+/// # Panics
+///
+/// [`RngCore::fill_bytes`] panics if the reseed interval has been reached.
+/// Use [`RngCore::try_fill_bytes`] to handle this case.
+#[cfg(feature = "rand_core")]
+impl<D> RngCore for HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("HMAC_DRBG error");
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<D> CryptoRng for HmacDrbg<D> where D: Digest + BlockSizeUser + FixedOutputReset {}
+
+#[cfg(feature = "rand_core")]
+impl From<Error> for rand_core::Error {
+    fn from(err: Error) -> rand_core::Error {
+        let code = rand_core::Error::CUSTOM_START + err as u32;
+        NonZeroU32::new(code).expect("nonzero error code").into()
+    }
+}
+
+/// Number of zero bytes needed to pad `len` bytes to a multiple of the
+/// block size of `D`.
+fn padding_len<D: BlockSizeUser>(len: usize) -> usize {
+    let block_size = D::BlockSize::USIZE;
+    (block_size - len % block_size) % block_size
+}
+
+/// Check the length of entropy input against the given security strength.
+fn check_entropy_input(entropy_input: &[u8], security_strength: usize) -> Result<()> {
+    if entropy_input.len() * 8 < security_strength {
+        return Err(Error::InsufficientEntropy);
+    }
+
+    check_length(entropy_input)
+}
+
+/// Check input length against the maximum permitted by NIST SP800-90A.
+fn check_length(input: &[u8]) -> Result<()> {
+    if input.len() as u64 > MAX_LENGTH {
+        return Err(Error::InputTooLong);
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 #![forbid(unsafe_code, clippy::unwrap_used)]
 #![warn(missing_docs, rust_2018_idioms)]
@@ -37,17 +38,26 @@
 //! assert_eq!(k.as_slice(), &RFC6979_EXPECTED_K);
 //! ```
 
+#[cfg(feature = "dev")]
+extern crate alloc;
+
+#[cfg(feature = "dev")]
+pub mod dev;
+
 mod ct_cmp;
+mod error;
+mod hmac_drbg;
 
+pub use crate::{
+    error::{Error, Result},
+    hmac_drbg::HmacDrbg,
+};
 pub use hmac::digest::generic_array::typenum::consts;
 
-use hmac::{
-    digest::{
-        core_api::BlockSizeUser,
-        generic_array::{ArrayLength, GenericArray},
-        Digest, FixedOutput, FixedOutputReset, Mac,
-    },
-    SimpleHmac,
+use hmac::digest::{
+    core_api::BlockSizeUser,
+    generic_array::{ArrayLength, GenericArray},
+    Digest, FixedOutput, FixedOutputReset,
 };
 
 /// Array of bytes representing a scalar serialized as a big endian integer.
@@ -59,8 +69,15 @@ pub type ByteArray<Size> = GenericArray<u8, Size>;
 ///
 /// - `x`: secret key
 /// - `n`: field modulus
-/// - `h`: hash/digest of input message: must be reduced modulo `n` in advance
+/// - `h`: hash/digest of input message: must be reduced modulo `n` in advance,
+///   e.g. using [`bits2octets`]
 /// - `data`: additional associated data, e.g. CSRNG output used as added entropy
+///
+/// When the bit length of `n` (`qlen`) is not a multiple of 8, the output of
+/// `HMAC_DRBG` is truncated to its leftmost `qlen` bits as described in
+/// [RFC6979 § 3.2] step h.3.
+///
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
 #[inline]
 pub fn generate_k<D, N>(
     x: &ByteArray<N>,
@@ -71,12 +88,83 @@ pub fn generate_k<D, N>(
 where
     D: Digest + BlockSizeUser + FixedOutput<OutputSize = N> + FixedOutputReset,
     N: ArrayLength<u8>,
+{
+    generate_k_mixed::<D, N>(x, n, h, data)
+}
+
+/// Deterministically generate ephemeral scalar `k` using a digest whose
+/// output size may differ from the size of the scalar.
+///
+/// This permits digest/curve pairings such as P-256 with SHA-512 or P-384
+/// with SHA-256, which are allowed by [RFC6979 § 3.2]: `HMAC_DRBG` output is
+/// concatenated until it is at least as long as `n`, then converted using
+/// [`bits2int`].
+///
+/// Accepts the same parameters as [`generate_k`], and produces identical
+/// output when `D::OutputSize` is `N`.
+///
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+#[inline]
+pub fn generate_k_mixed<D, N>(
+    x: &ByteArray<N>,
+    n: &ByteArray<N>,
+    h: &ByteArray<N>,
+    data: &[u8],
+) -> ByteArray<N>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    N: ArrayLength<u8>,
 {
     let mut hmac_drbg = HmacDrbg::<D>::new(x, h, data);
+    generate_k_with_drbg(&mut hmac_drbg, n)
+}
 
+/// Generate ephemeral scalar `k` using the hedged construction described in
+/// [draft-irtf-cfrg-det-sigs-with-noise], which combines the RFC6979
+/// deterministic construction with a fresh random string `z`.
+///
+/// The output remains secure if either the RNG or the RFC6979 derivation is
+/// sound, and mixing in fresh randomness frustrates fault attacks which rely on
+/// repeated deterministic signatures of the same message.
+///
+/// Accepts the following parameters and inputs:
+///
+/// - `x`: secret key
+/// - `n`: field modulus
+/// - `h`: hash/digest of input message: must be reduced modulo `n` in advance,
+///   e.g. using [`bits2octets`]
+/// - `z`: random string, which must be freshly generated by a CSRNG for each
+///   signature, e.g. the same length as `x`
+///
+/// See [`HmacDrbg::new_hedged`] for details of the construction.
+///
+/// [draft-irtf-cfrg-det-sigs-with-noise]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-det-sigs-with-noise
+#[inline]
+pub fn generate_k_hedged<D, N>(
+    x: &ByteArray<N>,
+    n: &ByteArray<N>,
+    h: &ByteArray<N>,
+    z: &[u8],
+) -> ByteArray<N>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    N: ArrayLength<u8>,
+{
+    let mut hmac_drbg = HmacDrbg::<D>::new_hedged(x, h, z);
+    generate_k_with_drbg(&mut hmac_drbg, n)
+}
+
+/// Generate `k` from an instantiated `HMAC_DRBG` as described in
+/// RFC6979 § 3.2 step h.
+fn generate_k_with_drbg<D, N>(hmac_drbg: &mut HmacDrbg<D>, n: &ByteArray<N>) -> ByteArray<N>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    N: ArrayLength<u8>,
+{
     loop {
-        let mut k = ByteArray::<N>::default();
-        hmac_drbg.fill_bytes(&mut k);
+        let mut t = ByteArray::<N>::default();
+        hmac_drbg.fill_bytes(&mut t);
+        let k = bits2int(&t, n);
 
         let k_is_zero = ct_cmp::ct_eq(&k, &ByteArray::default());
         if (!k_is_zero & ct_cmp::ct_lt(&k, n)).into() {
@@ -85,65 +173,62 @@ where
     }
 }
 
-/// Internal implementation of `HMAC_DRBG` as described in NIST SP800-90A.
+/// Convert a bit string into a big endian integer of the same size as `n`,
+/// keeping only its leftmost `qlen` bits, where `qlen` is the bit length of
+/// `n`, as described in [RFC6979 § 2.3.2].
 ///
-/// <https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final>
+/// Input which is shorter than `qlen` bits is zero-padded on the left.
 ///
-/// This is a HMAC-based deterministic random bit generator used compute a
-/// deterministic ephemeral scalar `k`.
-pub struct HmacDrbg<D>
+/// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
+pub fn bits2int<N>(bits: &[u8], n: &ByteArray<N>) -> ByteArray<N>
 where
-    D: Digest + BlockSizeUser + FixedOutputReset,
+    N: ArrayLength<u8>,
 {
-    /// HMAC key `K` (see RFC 6979 Section 3.2.c)
-    k: SimpleHmac<D>,
+    let qlen = bit_length(n);
+    let qlen_bytes = (qlen + 7) / 8;
+    let mut int = ByteArray::<N>::default();
+
+    // Truncate to whole bytes first: any bytes beyond `qlen_bytes` are
+    // discarded by the right shift below in their entirety
+    let bits = &bits[..bits.len().min(qlen_bytes)];
+    int[(N::USIZE - bits.len())..].copy_from_slice(bits);
+
+    // Discard the remaining rightmost bits in excess of `qlen`
+    let shift = (bits.len() * 8).saturating_sub(qlen);
+    if shift > 0 {
+        let mut carry = 0;
+        for byte in int.iter_mut() {
+            let next_carry = *byte << (8 - shift);
+            *byte = (*byte >> shift) | carry;
+            carry = next_carry;
+        }
+    }
 
-    /// Chaining value `V` (see RFC 6979 Section 3.2.c)
-    v: GenericArray<u8, D::OutputSize>,
+    int
 }
 
-impl<D> HmacDrbg<D>
+/// Convert a bit string into an octet string reduced modulo `n`, as
+/// described in [RFC6979 § 2.3.4].
+///
+/// This is the form the message hash `h` must be in when passed to
+/// [`generate_k`].
+///
+/// [RFC6979 § 2.3.4]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.4
+pub fn bits2octets<N>(bits: &[u8], n: &ByteArray<N>) -> ByteArray<N>
 where
-    D: Digest + BlockSizeUser + FixedOutputReset,
+    N: ArrayLength<u8>,
 {
-    /// Initialize `HMAC_DRBG`
-    pub fn new(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
-        let mut k = SimpleHmac::new(&Default::default());
-        let mut v = GenericArray::default();
-
-        for b in &mut v {
-            *b = 0x01;
-        }
-
-        for i in 0..=1 {
-            k.update(&v);
-            k.update(&[i]);
-            k.update(entropy_input);
-            k.update(nonce);
-            k.update(personalization_string);
-            k = SimpleHmac::new_from_slice(&k.finalize().into_bytes()).expect("HMAC error");
-
-            // Steps 3.2.e,g: v = HMAC_k(v)
-            k.update(&v);
-            v = k.finalize_reset().into_bytes();
-        }
-
-        Self { k, v }
-    }
-
-    /// Write the next `HMAC_DRBG` output to the given byte slice.
-    pub fn fill_bytes(&mut self, out: &mut [u8]) {
-        for out_chunk in out.chunks_mut(self.v.len()) {
-            self.k.update(&self.v);
-            self.v = self.k.finalize_reset().into_bytes();
-            out_chunk.copy_from_slice(&self.v[..out_chunk.len()]);
-        }
+    // `bits2int` outputs a value of at most `qlen` bits, i.e. less than `2n`,
+    // so a single conditional subtraction suffices to reduce it
+    let z1 = bits2int(bits, n);
+    let z2 = ct_cmp::ct_sub(&z1, n);
+    ct_cmp::ct_select(&z2, &z1, ct_cmp::ct_lt(&z1, n))
+}
 
-        self.k.update(&self.v);
-        self.k.update(&[0x00]);
-        self.k =
-            SimpleHmac::new_from_slice(&self.k.finalize_reset().into_bytes()).expect("HMAC error");
-        self.k.update(&self.v);
-        self.v = self.k.finalize_reset().into_bytes();
+/// Compute the bit length of the given big endian integer.
+fn bit_length(n: &[u8]) -> usize {
+    match n.iter().position(|&byte| byte != 0) {
+        Some(i) => (n.len() - i) * 8 - n[i].leading_zeros() as usize,
+        None => 0,
     }
 }
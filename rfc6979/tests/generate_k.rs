@@ -0,0 +1,311 @@
+//! RFC6979 test vectors for digests whose output size differs from the
+//! scalar size, including curves whose order is not a multiple of 8 bits.
+
+use hex_literal::hex;
+use rfc6979::{
+    bits2int, bits2octets,
+    consts::{U21, U32, U48, U66},
+    generate_k_hedged, generate_k_mixed, ByteArray,
+};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+/// NIST P-256 scalar field modulus.
+const NIST_P256_MODULUS: [u8; 32] =
+    hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+
+/// Private key for the RFC6979 NIST P-256 test cases.
+const NIST_P256_KEY: [u8; 32] =
+    hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+/// NIST P-384 scalar field modulus.
+const NIST_P384_MODULUS: [u8; 48] = hex!(
+    "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+    "C7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973"
+);
+
+/// Private key for the RFC6979 NIST P-384 test cases.
+const NIST_P384_KEY: [u8; 48] = hex!(
+    "6B9D3DAD2E1B8C1C05B19875B6659F4DE23C3B667BF297BA"
+    "9AA47740787137D896D5724E4C70A825F872C9EA60D2EDF5"
+);
+
+/// NIST P-521 scalar field modulus.
+const NIST_P521_MODULUS: [u8; 66] = hex!(
+    "01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+    "FA51868783BF2F966B7FCC0148F709A5D03BB5C9B8899C47AEBB6FB71E91386409"
+);
+
+/// Private key for the RFC6979 NIST P-521 test cases.
+const NIST_P521_KEY: [u8; 66] = hex!(
+    "00FAD06DAA62BA3B25D2FB40133DA757205DE67F5BB0018FEE8C86E1B68C7E75CA"
+    "A896EB32F1F47C70855836A6D16FCC1466F6D8FBEC67DB89EC0C08B0E996B83538"
+);
+
+/// Scalar field modulus of the K-163 curve used in RFC6979 Appendix A.1.
+const K163_MODULUS: [u8; 21] = hex!("04000000000000000000020108A2E0CC0D99F8A5EF");
+
+/// Private key for the RFC6979 Appendix A.1 detailed example.
+const K163_KEY: [u8; 21] = hex!("009A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+
+#[test]
+fn bits2int_truncates_to_qlen_bits() {
+    let n = ByteArray::<U66>::clone_from_slice(&NIST_P521_MODULUS);
+
+    // Inputs which are shorter than qlen are left-padded with zeroes
+    let bits = [0xFF; 64];
+    let int = bits2int(&bits, &n);
+    assert_eq!(&int[..2], &[0, 0]);
+    assert_eq!(&int[2..], &bits);
+
+    // Inputs which are longer than qlen keep only their leftmost qlen bits
+    let bits = [0xFF; 66];
+    let int = bits2int(&bits, &n);
+    assert_eq!(int[0], 0x01);
+    assert_eq!(&int[1..], &[0xFF; 65]);
+
+    let mut bits = [0u8; 128];
+    bits[0] = 0x80;
+    let int = bits2int(&bits, &n);
+    assert_eq!(int[0], 0x01);
+    assert_eq!(&int[1..], &[0; 65]);
+}
+
+/// RFC6979 Appendix A.1: detailed example on K-163 with SHA-256
+#[test]
+fn k163_detailed_example() {
+    let n = ByteArray::<U21>::clone_from_slice(&K163_MODULUS);
+    let x = ByteArray::<U21>::clone_from_slice(&K163_KEY);
+    let h = Sha256::digest(b"sample");
+
+    assert_eq!(
+        bits2octets(&h, &n).as_slice(),
+        hex!("01795EDF0D54DB760F156D0DAC04C0322B3A204224")
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha256, U21>(&x, &n, &bits2octets(&h, &n), b"").as_slice(),
+        hex!("023AF4074C90A02B3FE61D286D5C87F425E6BDD81B")
+    );
+}
+
+/// RFC6979 Appendix A.2.5: ECDSA, 256 Bits (Prime Field)
+#[test]
+fn nist_p256() {
+    let n = ByteArray::<U32>::clone_from_slice(&NIST_P256_MODULUS);
+    let x = ByteArray::<U32>::clone_from_slice(&NIST_P256_KEY);
+
+    assert_eq!(
+        generate_k_mixed::<Sha1, U32>(&x, &n, &bits2octets(&Sha1::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!("882905F1227FD620FBF2ABF21244F0BA83D0DC3A9103DBBEE43A1FB858109DB4")
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha224, U32>(&x, &n, &bits2octets(&Sha224::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!("103F90EE9DC52E5E7FB5132B7033C63066D194321491862059967C715985D473")
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha384, U32>(&x, &n, &bits2octets(&Sha384::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!("09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4")
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha512, U32>(&x, &n, &bits2octets(&Sha512::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5")
+    );
+}
+
+/// RFC6979 Appendix A.2.6: ECDSA, 384 Bits (Prime Field)
+#[test]
+fn nist_p384() {
+    let n = ByteArray::<U48>::clone_from_slice(&NIST_P384_MODULUS);
+    let x = ByteArray::<U48>::clone_from_slice(&NIST_P384_KEY);
+
+    assert_eq!(
+        generate_k_mixed::<Sha256, U48>(&x, &n, &bits2octets(&Sha256::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+            "180AE9F9AEC5438A44BC159A1FCB277C7BE54FA2"
+            "0E7CF404B490650A8ACC414E375572342863C899F9F2EDF9747A9B60"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha512, U48>(&x, &n, &bits2octets(&Sha512::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+            "92FC3C7183A883E24216D1141F1A8976C5B0DD797DFA597E"
+            "3D7B32198BD35331A4E966532593A52980D0E3AAA5E10EC3"
+        )
+    );
+}
+
+/// RFC6979 Appendix A.2.7: ECDSA, 521 Bits (Prime Field)
+#[test]
+fn nist_p521() {
+    let n = ByteArray::<U66>::clone_from_slice(&NIST_P521_MODULUS);
+    let x = ByteArray::<U66>::clone_from_slice(&NIST_P521_KEY);
+
+    assert_eq!(
+        generate_k_mixed::<Sha1, U66>(&x, &n, &bits2octets(&Sha1::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+        "0089C071B419E1C2820962321787258469511958E80582E95D8378E0C2CCDB3CB4"
+        "2BEDE42F50E3FA3C71F5A76724281D31D9C89F0F91FC1BE4918DB1C03A5838D0F9"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha1, U66>(&x, &n, &bits2octets(&Sha1::digest(b"test"), &n), b"")
+            .as_slice(),
+        hex!(
+        "00BB9F2BF4FE1038CCF4DABD7139A56F6FD8BB1386561BD3C6A4FC818B20DF5DDB"
+        "A80795A947107A1AB9D12DAA615B1ADE4F7A9DC05E8E6311150F47F5C57CE8B222"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha224, U66>(&x, &n, &bits2octets(&Sha224::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+        "0121415EC2CD7726330A61F7F3FA5DE14BE9436019C4DB8CB4041F3B54CF31BE04"
+        "93EE3F427FB906393D895A19C9523F3A1D54BB8702BD4AA9C99DAB2597B92113F3"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha224, U66>(&x, &n, &bits2octets(&Sha224::digest(b"test"), &n), b"")
+            .as_slice(),
+        hex!(
+        "0040D09FCF3C8A5F62CF4FB223CBBB2B9937F6B0577C27020A99602C25A0113698"
+        "7E452988781484EDBBCF1C47E554E7FC901BC3085E5206D9F619CFF07E73D6F706"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha256, U66>(&x, &n, &bits2octets(&Sha256::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+        "00EDF38AFCAAECAB4383358B34D67C9F2216C8382AAEA44A3DAD5FDC9C32575761"
+        "793FEF24EB0FC276DFC4F6E3EC476752F043CF01415387470BCBD8678ED2C7E1A0"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha256, U66>(&x, &n, &bits2octets(&Sha256::digest(b"test"), &n), b"")
+            .as_slice(),
+        hex!(
+        "001DE74955EFAABC4C4F17F8E84D881D1310B5392D7700275F82F145C61E843841"
+        "AF09035BF7A6210F5A431A6A9E81C9323354A9E69135D44EBD2FCAA7731B909258"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha384, U66>(&x, &n, &bits2octets(&Sha384::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+        "01546A108BC23A15D6F21872F7DED661FA8431DDBD922D0DCDB77CC878C8553FFA"
+        "D064C95A920A750AC9137E527390D2D92F153E66196966EA554D9ADFCB109C4211"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha384, U66>(&x, &n, &bits2octets(&Sha384::digest(b"test"), &n), b"")
+            .as_slice(),
+        hex!(
+        "01F1FC4A349A7DA9A9E116BFDD055DC08E78252FF8E23AC276AC88B1770AE0B5DC"
+        "EB1ED14A4916B769A523CE1E90BA22846AF11DF8B300C38818F713DADD85DE0C88"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha512, U66>(&x, &n, &bits2octets(&Sha512::digest(b"sample"), &n), b"")
+            .as_slice(),
+        hex!(
+        "01DAE2EA071F8110DC26882D4D5EAE0621A3256FC8847FB9022E2B7D28E6F10198"
+        "B1574FDD03A9053C08A1854A168AA5A57470EC97DD5CE090124EF52A2F7ECBFFD3"
+        )
+    );
+
+    assert_eq!(
+        generate_k_mixed::<Sha512, U66>(&x, &n, &bits2octets(&Sha512::digest(b"test"), &n), b"")
+            .as_slice(),
+        hex!(
+        "016200813020EC986863BEDFC1B121F605C1215645018AEA1A7B215A564DE9EB1B"
+        "38A67AA1128B80CE391C4FB71187654AAA3431027BFC7F395766CA988C964DC56D"
+        )
+    );
+}
+
+#[test]
+fn hedged() {
+    let n = ByteArray::<U32>::clone_from_slice(&NIST_P256_MODULUS);
+    let x = ByteArray::<U32>::clone_from_slice(&NIST_P256_KEY);
+    let h = bits2octets(&Sha256::digest(b"sample"), &n);
+
+    let k1 = generate_k_hedged::<Sha256, U32>(&x, &n, &h, &[0x01; 32]);
+    let k2 = generate_k_hedged::<Sha256, U32>(&x, &n, &h, &[0x02; 32]);
+    let k3 = generate_k_hedged::<Sha512, U32>(&x, &n, &h, &[0x01; 32]);
+
+    // Output is deterministic for a given random string
+    assert_eq!(
+        k1,
+        generate_k_hedged::<Sha256, U32>(&x, &n, &h, &[0x01; 32])
+    );
+    assert_ne!(k1, k2);
+    assert_ne!(k1, k3);
+
+    // Hedged output differs from RFC6979 output, even with the same added data
+    assert_ne!(k1, generate_k_mixed::<Sha256, U32>(&x, &n, &h, &[0x01; 32]));
+
+    for k in [k1, k2, k3] {
+        assert!(k.as_slice() < n.as_slice());
+    }
+}
+
+/// Hedged `k` for the RFC6979 NIST P-256 and P-384 `"sample"` test cases
+/// with fixed random strings.
+///
+/// No test vectors are published for these inputs, so the expected values
+/// were computed with an independent Python implementation of § 4 of
+/// draft-irtf-cfrg-det-sigs-with-noise. A 31-byte `Z` makes
+/// `V || 0x00 || Z` exactly one SHA-256 block, so it needs no padding.
+#[test]
+fn hedged_known_answers() {
+    let n = ByteArray::<U32>::clone_from_slice(&NIST_P256_MODULUS);
+    let x = ByteArray::<U32>::clone_from_slice(&NIST_P256_KEY);
+    let h256 = bits2octets(&Sha256::digest(b"sample"), &n);
+
+    assert_eq!(
+        generate_k_hedged::<Sha256, U32>(&x, &n, &h256, &[0x01; 32]).as_slice(),
+        hex!("B48FD54E74077A607113ACD109DE68510C4754FE2E8A48A90173B922848726A9")
+    );
+    assert_eq!(
+        generate_k_hedged::<Sha512, U32>(&x, &n, &h256, &[0x01; 32]).as_slice(),
+        hex!("6267571E1704B55EE73CCD4DD5E66D3A6465CA8A56AAD7B42D36D42235FAC83C")
+    );
+    assert_eq!(
+        generate_k_hedged::<Sha256, U32>(&x, &n, &h256, &[0x02; 31]).as_slice(),
+        hex!("63B08C0CCDB61AF7BA2117F738BDE77B9B6F48113969712995917E791168A7B9")
+    );
+    assert_eq!(
+        generate_k_hedged::<Sha512, U32>(&x, &n, &h256, &[0x02; 31]).as_slice(),
+        hex!("5E3578ABF24D263FDCCAB7C0368227524BD33FE78A94F262770CDE5DEB4B7E91")
+    );
+
+    let n = ByteArray::<U48>::clone_from_slice(&NIST_P384_MODULUS);
+    let x = ByteArray::<U48>::clone_from_slice(&NIST_P384_KEY);
+    let h384 = bits2octets(&Sha384::digest(b"sample"), &n);
+    assert_eq!(
+        generate_k_hedged::<Sha384, U48>(&x, &n, &h384, &[0x01; 48]).as_slice(),
+        hex!(
+            "DF7A3E8808CB2C40DF1E85D3321FD99F48A16582DD6B574F"
+            "38A034087C5195EB01507886038A11733785BE1B2456D97A"
+        )
+    );
+}
@@ -0,0 +1,153 @@
+//! NIST SP800-90A `HMAC_DRBG` tests.
+
+use hex_literal::hex;
+use rfc6979::{Error, HmacDrbg};
+use sha1::Sha1;
+use sha2::Sha256;
+
+/// NIST CAVP `HMAC_DRBG.rsp` [SHA-256], no prediction resistance, COUNT = 0
+const ENTROPY_INPUT: [u8; 32] =
+    hex!("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488");
+const NONCE: [u8; 16] = hex!("659ba96c601dc69fc902940805ec0ca8");
+const RETURNED_BITS: [u8; 128] = hex!(
+    "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89"
+    "d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1"
+    "07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668"
+    "961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8"
+);
+
+#[test]
+fn cavp_sha256_no_reseed() {
+    let mut drbg = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    let mut out = [0u8; 128];
+    drbg.generate(&mut out, &[]).unwrap();
+    drbg.generate(&mut out, &[]).unwrap();
+    assert_eq!(out, RETURNED_BITS);
+    assert_eq!(drbg.reseed_counter(), 3);
+}
+
+#[test]
+fn new_matches_instantiate() {
+    let mut drbg = HmacDrbg::<Sha256>::new(&ENTROPY_INPUT, &NONCE, &[]);
+    let mut out = [0u8; 128];
+    drbg.fill_bytes(&mut out);
+    drbg.fill_bytes(&mut out);
+    assert_eq!(out, RETURNED_BITS);
+}
+
+/// `HMAC_DRBG` output after [`HmacDrbg::new_hedged`] with the RFC6979 NIST
+/// P-256 key, the reduced SHA-256 digest of `"sample"` and `Z = 0x01 * 32`.
+///
+/// The draft-irtf-cfrg-det-sigs-with-noise ECDSA test vectors are not
+/// published for these inputs, so this was computed with an independent
+/// Python implementation of the formula in § 4 of the draft.
+#[test]
+fn new_hedged() {
+    let x = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+    let h = hex!("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+    let mut drbg = HmacDrbg::<Sha256>::new_hedged(&x, &h, &[0x01; 32]);
+    let mut out = [0u8; 64];
+    drbg.fill_bytes(&mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "b48fd54e74077a607113acd109de68510c4754fe2e8a48a90173b922848726a9"
+            "0dfe0c49090d6bb7446ec3f11748d1dafbda676aa39a8c3e000699de692db71c"
+        )
+    );
+
+    // Output differs for another random string, and from RFC6979 with the
+    // random string as additional data
+    let mut other = [0u8; 64];
+    HmacDrbg::<Sha256>::new_hedged(&x, &h, &[0x02; 32]).fill_bytes(&mut other);
+    assert_ne!(out, other);
+    HmacDrbg::<Sha256>::new(&x, &h, &[0x01; 32]).fill_bytes(&mut other);
+    assert_ne!(out, other);
+}
+
+#[test]
+fn prediction_resistance_reseeds() {
+    let entropy_input_pr = [0x42; 32];
+    let mut drbg1 = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    let mut drbg2 = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+
+    let mut out1 = [0u8; 64];
+    drbg1
+        .generate_with_prediction_resistance(&mut out1, &entropy_input_pr, b"additional")
+        .unwrap();
+
+    let mut out2 = [0u8; 64];
+    drbg2.reseed(&entropy_input_pr, b"additional").unwrap();
+    drbg2.generate(&mut out2, &[]).unwrap();
+
+    assert_eq!(out1, out2);
+    assert_eq!(drbg1.reseed_counter(), 2);
+}
+
+#[test]
+fn security_strength() {
+    assert_eq!(
+        HmacDrbg::<Sha256>::instantiate(100, &ENTROPY_INPUT, &NONCE, &[])
+            .unwrap()
+            .security_strength(),
+        112
+    );
+    assert_eq!(
+        HmacDrbg::<Sha1>::instantiate(192, &ENTROPY_INPUT, &NONCE, &[]).err(),
+        Some(Error::SecurityStrength)
+    );
+    assert_eq!(
+        HmacDrbg::<Sha256>::instantiate(257, &ENTROPY_INPUT, &NONCE, &[]).err(),
+        Some(Error::SecurityStrength)
+    );
+    assert_eq!(
+        HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT[..31], &NONCE, &[]).err(),
+        Some(Error::InsufficientEntropy)
+    );
+    assert_eq!(
+        HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE[..15], &[]).err(),
+        Some(Error::InsufficientNonce)
+    );
+
+    let mut drbg = HmacDrbg::<Sha256>::instantiate(128, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    assert_eq!(drbg.reseed(&[0; 15], &[]), Err(Error::InsufficientEntropy));
+    assert_eq!(drbg.reseed(&[0; 16], &[]), Ok(()));
+}
+
+#[test]
+fn reseed_interval() {
+    let mut drbg = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    assert_eq!(drbg.set_reseed_interval(0), Err(Error::ReseedInterval));
+    drbg.set_reseed_interval(2).unwrap();
+
+    let mut out = [0u8; 32];
+    drbg.generate(&mut out, &[]).unwrap();
+    drbg.generate(&mut out, &[]).unwrap();
+    assert_eq!(drbg.generate(&mut out, &[]), Err(Error::ReseedRequired));
+
+    drbg.reseed(&ENTROPY_INPUT, &[]).unwrap();
+    assert_eq!(drbg.generate(&mut out, &[]), Ok(()));
+}
+
+#[test]
+fn request_too_long() {
+    let mut drbg = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    let mut out = [0u8; 65537];
+    assert_eq!(drbg.generate(&mut out, &[]), Err(Error::RequestTooLong));
+    assert_eq!(drbg.reseed_counter(), 1);
+}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn rng_core() {
+    use rand_core::RngCore;
+
+    let mut drbg = HmacDrbg::<Sha256>::instantiate(256, &ENTROPY_INPUT, &NONCE, &[]).unwrap();
+    let mut out = [0u8; 128];
+    RngCore::fill_bytes(&mut drbg, &mut out);
+    RngCore::fill_bytes(&mut drbg, &mut out);
+    assert_eq!(out, RETURNED_BITS);
+
+    drbg.set_reseed_interval(2).unwrap();
+    assert!(drbg.try_fill_bytes(&mut out).is_err());
+}
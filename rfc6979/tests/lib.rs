@@ -1,14 +1,24 @@
-//! Smoke tests which use `MockCurve`
+//! NIST CAVP `HMAC_DRBG` known-answer tests
 
 #![cfg(feature = "dev")]
 
-use elliptic_curve::dev::MockCurve;
+#[test]
+fn hmac_drbg_cavp() {
+    assert_eq!(rfc6979::dev::run(include_str!("data/HMAC_DRBG.rsp")), 30);
+}
 
-type Signature = ecdsa::Signature<MockCurve>;
-type SignatureBytes = ecdsa::SignatureBytes<MockCurve>;
+#[test]
+fn hmac_drbg_cavp_pr_true() {
+    assert_eq!(
+        rfc6979::dev::run(include_str!("data/HMAC_DRBG_pr_true.rsp")),
+        15
+    );
+}
 
 #[test]
-fn rejects_all_zero_signature() {
-    let all_zero_bytes = SignatureBytes::default();
-    assert!(Signature::try_from(all_zero_bytes.as_ref()).is_err());
+fn hmac_drbg_cavp_pr_false() {
+    assert_eq!(
+        rfc6979::dev::run(include_str!("data/HMAC_DRBG_pr_false.rsp")),
+        15
+    );
 }